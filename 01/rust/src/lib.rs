@@ -0,0 +1,258 @@
+// Day 01: Calorie Counting.
+use common::records::{records, Record};
+use common::stream::for_each_record;
+use common::{Answer, ParseError, Part, ReadError, Rng, Size, Solution};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+// Statistics of the whole calories inventory, beyond top elves.
+pub mod stats;
+
+// Puzzle day number.
+pub const DAY: u8 = 1;
+
+// Calories sum of every elf, in the order they appear in the input.
+pub type Input = Vec<u32>;
+
+// Calories of one item, a line with index `i` in the input. Items are u64 like elf totals, even though
+// `parse` needs each elf to fit into u32, so statistics can describe any elf whose total fits into u64.
+fn item_calories(i: usize, line: &str) -> Result<u64, ParseError> {
+    line.parse::<u64>()
+        .map_err(|_| ParseError::in_line(DAY, i, line, line, "calories number or empty line"))
+}
+
+// Everything one elf carries, added up item by item. Total is u64, so it's right even when an elf carries
+// more than u32 can hold, `parse` refuses such elves after that and statistics use it as it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Inventory {
+    // Amount of items, there is at least one.
+    pub items: usize,
+    // Calories of all items together.
+    pub total: u64,
+    // Calories of the biggest item.
+    pub largest: u64,
+}
+
+// Inventory of one elf. Each line of the record is calories of one item.
+fn inventory(record: &Record) -> Result<Inventory, ParseError> {
+    let mut inventory = Inventory {
+        items: 0,
+        total: 0,
+        largest: 0,
+    };
+
+    for (i, line) in record.lines() {
+        // Parse the value and add it to the sum.
+        let calories = item_calories(i, line)?;
+        inventory.items += 1;
+        inventory.total = inventory.total.checked_add(calories).ok_or_else(|| {
+            ParseError::in_line(
+                DAY,
+                i,
+                line,
+                line,
+                "calories that keep elf total within u64",
+            )
+        })?;
+        inventory.largest = inventory.largest.max(calories);
+    }
+
+    Ok(inventory)
+}
+
+// Calories sum of one elf, it has to fit into u32.
+fn elf_sum(record: &Record) -> Result<u32, ParseError> {
+    let total = inventory(record)?.total;
+    u32::try_from(total).map_err(|_| {
+        // No single item is wrong, so point at the first one of the elf.
+        let (i, line) = record.lines().next().unwrap_or((record.line_index, ""));
+        let expected = format!("elf carrying at most {} calories in total", u32::MAX);
+        ParseError::in_line(DAY, i, line, line, expected)
+    })
+}
+
+// Parse input into a list of calories sums.
+pub fn parse(file: &str) -> Result<Input, ParseError> {
+    // Elves are separated by blank lines, the last one counts even if input doesn't end with a blank line.
+    records(file).map(|record| elf_sum(&record)).collect()
+}
+
+// Call `f` with index and calories sum of every elf, reading input line by line.
+fn each_elf(reader: impl BufRead, mut f: impl FnMut(usize, u32)) -> Result<(), ReadError> {
+    let mut index = 0;
+    for_each_record(reader, |record| {
+        f(index, elf_sum(&record)?);
+        index += 1;
+        Ok(())
+    })
+}
+
+// Elf with its place in the input (from 0, same as in `Input`) and calories it carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RankedElf {
+    pub index: usize,
+    pub calories: u32,
+}
+
+// Top `n` elves carrying the most calories, collected one elf at a time so streaming can use it too.
+// Elves with the same total are never merged, the one that comes first in the input ranks higher.
+#[derive(Debug, Clone)]
+pub struct Ranking {
+    n: usize,
+    // Best elves so far as (calories, Reverse(index)), so an earlier elf is bigger when calories are equal.
+    // Reverse makes it a min-heap: the worst of them is on top and gets dropped when there are more than `n`.
+    heap: BinaryHeap<Reverse<(u32, Reverse<usize>)>>,
+}
+
+impl Ranking {
+    pub fn new(n: usize) -> Self {
+        Ranking {
+            n,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    // Add next elf, it stays only if it's one of the top `n` so far.
+    pub fn add(&mut self, index: usize, calories: u32) {
+        self.heap.push(Reverse((calories, Reverse(index))));
+        if self.heap.len() > self.n {
+            self.heap.pop();
+        }
+    }
+
+    // Elves from the one with the most calories down.
+    pub fn into_elves(self) -> Vec<RankedElf> {
+        // Sorted vector of Reverse goes from the biggest (calories, Reverse(index)) to the smallest.
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((calories, Reverse(index)))| RankedElf { index, calories })
+            .collect()
+    }
+}
+
+// Top `n` elves carrying the most calories, best one first. Fewer if there are less than `n` elves.
+pub fn top_elves(sums: &Input, n: usize) -> Vec<RankedElf> {
+    let mut ranking = Ranking::new(n);
+    for (index, calories) in sums.iter().enumerate() {
+        ranking.add(index, *calories);
+    }
+
+    ranking.into_elves()
+}
+
+// Same as top_elves, but reads input line by line and keeps only the top `n` elves in memory.
+pub fn top_elves_streaming(reader: impl BufRead, n: usize) -> Result<Vec<RankedElf>, ReadError> {
+    let mut ranking = Ranking::new(n);
+    each_elf(reader, |index, calories| ranking.add(index, calories))?;
+
+    Ok(ranking.into_elves())
+}
+
+// Total calories carried by ranked elves. Every elf fits into u32, but a few of them together might not.
+pub fn total_calories(elves: &[RankedElf]) -> u64 {
+    elves.iter().map(|elf| u64::from(elf.calories)).sum()
+}
+
+// Ranking with place, number and calories of every elf on its own line, then their total.
+pub fn ranking_report(elves: &[RankedElf]) -> String {
+    let mut report = String::new();
+    for (place, elf) in elves.iter().enumerate() {
+        report += &format!(
+            "{}. Elf number: {}, got: {} callories!\n",
+            place + 1,
+            elf.index,
+            elf.calories
+        );
+    }
+    report += &format!(
+        "Total callories for top {} elves: {}\n",
+        elves.len(),
+        total_calories(elves)
+    );
+
+    report
+}
+
+// Index of the elf carrying the most calories and amount of those calories.
+// If several elves carry the most, it's the first of them, like at the top of `top_elves`.
+pub fn max_calories(sums: &Input) -> Option<(usize, u32)> {
+    top_elves(sums, 1)
+        .first()
+        .map(|elf| (elf.index, elf.calories))
+}
+
+// Calories carried by the elf that carries the most.
+pub fn part_one(sums: &Input) -> u32 {
+    max_calories(sums)
+        .map(|(_, calories)| calories)
+        .unwrap_or(0)
+}
+
+// Total calories carried by top 3 elves. Elves with the same total all count.
+pub fn part_two(sums: &Input) -> u64 {
+    total_calories(&top_elves(sums, 3))
+}
+
+// Same as part_one, but reads input line by line and keeps only the biggest sum.
+pub fn part_one_streaming(reader: impl BufRead) -> Result<u32, ReadError> {
+    let mut max_calories: u32 = 0;
+    each_elf(reader, |_, sum| max_calories = max_calories.max(sum))?;
+
+    Ok(max_calories)
+}
+
+// Same as part_two, but reads input line by line and keeps only the top 3 elves.
+pub fn part_two_streaming(reader: impl BufRead) -> Result<u64, ReadError> {
+    Ok(total_calories(&top_elves_streaming(reader, 3)?))
+}
+
+// Random input with `size.amount` elves carrying from 1 to `size.width` items each.
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let elves: Vec<String> = (0..size.amount.max(1))
+        .map(|_| {
+            let items = rng.range(1..=size.width.max(1) as u64);
+            (0..items)
+                .map(|_| format!("{}\n", rng.range(1000..=70000)))
+                .collect()
+        })
+        .collect();
+
+    // Elves are separated by an empty line, just like in real inputs.
+    elves.join("\n")
+}
+
+// Solution of the day for the runner.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Calorie Counting";
+    type Input = Input;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(file: &str) -> Result<Input, ParseError> {
+        parse(file)
+    }
+
+    fn part_one(input: &Input) -> u32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> u64 {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> String {
+        generate(rng, size)
+    }
+
+    fn stream(part: Part, reader: &mut dyn BufRead) -> Option<Result<Answer, ReadError>> {
+        Some(match part {
+            Part::One => part_one_streaming(reader).map(Answer::from),
+            Part::Two => part_two_streaming(reader).map(Answer::from),
+        })
+    }
+}
@@ -0,0 +1,171 @@
+// Statistics of calories carried by elves: how many there are, how totals are spread, what items they carry.
+// Sums here are u64, so even generated inputs where one elf carries more than u32 can hold are fine.
+use crate::{inventory, Inventory};
+use common::records::records;
+use common::ParseError;
+use std::fmt;
+
+// Percentiles shown in the report.
+pub const PERCENTILES: [u32; 6] = [10, 25, 50, 75, 90, 99];
+
+// Amount of histogram bars in the report.
+pub const HISTOGRAM_BUCKETS: usize = 10;
+
+// Length of the longest histogram bar in characters.
+const BAR_WIDTH: usize = 50;
+
+// Parse input into inventories of every elf, in the order they appear in the input.
+// Unlike `parse`, an elf may carry more calories than fit into u32.
+pub fn inventories(file: &str) -> Result<Vec<Inventory>, ParseError> {
+    records(file).map(|record| inventory(&record)).collect()
+}
+
+// Range of elf totals (both ends included) and amount of elves in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub from: u64,
+    pub to: u64,
+    pub elves: usize,
+}
+
+// Statistics of all elves together.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    // Amount of elves.
+    pub elves: usize,
+    // Calories carried by all elves.
+    pub total: u64,
+    // Mean and median of elf totals.
+    pub mean: f64,
+    pub median: f64,
+    // Percentile and elf total at it, for every one of `PERCENTILES`.
+    pub percentiles: Vec<(u32, u64)>,
+    // Amount of items carried by all elves.
+    pub items: u64,
+    // Fewest, median and most items carried by one elf.
+    pub fewest_items: usize,
+    pub median_items: f64,
+    pub most_items: usize,
+    // Index of the elf (from 0, same as in `top_elves`) carrying the biggest single item, and its calories.
+    pub largest_item: (usize, u64),
+    // Elf totals split into `HISTOGRAM_BUCKETS` ranges of the same width, from the smallest total to the biggest.
+    pub histogram: Vec<Bucket>,
+}
+
+// Median of sorted values: middle one, or mean of two middle ones if there is an even amount of them.
+fn median(sorted: &[u64]) -> f64 {
+    let middle = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[middle - 1] as f64 + sorted[middle] as f64) / 2.0
+    } else {
+        sorted[middle] as f64
+    }
+}
+
+// Value at percentile `p` of sorted values, using the nearest rank: smallest value that at least p% of values
+// are less than or equal to. Always one of the values, unlike the median.
+fn percentile(sorted: &[u64], p: u32) -> u64 {
+    let rank = (sorted.len() * p as usize).div_ceil(100);
+    sorted[rank.max(1) - 1]
+}
+
+// Split sorted values into `buckets` ranges of the same width, from the smallest value to the biggest.
+pub fn histogram(sorted: &[u64], buckets: usize) -> Vec<Bucket> {
+    let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+        return Vec::new();
+    };
+    let buckets = buckets.max(1) as u64;
+    // Width is rounded up, so the biggest value always falls into the last range.
+    let width = (max - min) / buckets + 1;
+
+    let mut histogram: Vec<Bucket> = (0..buckets)
+        .map(|b| Bucket {
+            from: min + b * width,
+            to: min + (b + 1) * width - 1,
+            elves: 0,
+        })
+        .collect();
+    for value in sorted {
+        histogram[((value - min) / width) as usize].elves += 1;
+    }
+
+    histogram
+}
+
+// Statistics of elf inventories, None if there are no elves at all.
+pub fn stats(inventories: &[Inventory]) -> Option<Stats> {
+    let mut totals: Vec<u64> = inventories.iter().map(|elf| elf.total).collect();
+    totals.sort_unstable();
+    let mut items: Vec<u64> = inventories.iter().map(|elf| elf.items as u64).collect();
+    items.sort_unstable();
+
+    // First elf with the biggest item, same as `max_calories` picks the first of equal elves.
+    let largest_item = inventories
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|(_, elf)| elf.largest)
+        .map(|(index, elf)| (index, elf.largest))?;
+
+    let total: u64 = totals.iter().sum();
+    Some(Stats {
+        elves: inventories.len(),
+        total,
+        mean: total as f64 / inventories.len() as f64,
+        median: median(&totals),
+        percentiles: PERCENTILES
+            .iter()
+            .map(|p| (*p, percentile(&totals, *p)))
+            .collect(),
+        items: items.iter().sum(),
+        fewest_items: items[0] as usize,
+        median_items: median(&items),
+        most_items: items[items.len() - 1] as usize,
+        largest_item,
+        histogram: histogram(&totals, HISTOGRAM_BUCKETS),
+    })
+}
+
+// Report with one statistic per line, and histogram bars scaled so the biggest one is BAR_WIDTH long.
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Elves: {}", self.elves)?;
+        writeln!(f, "Total callories: {}", self.total)?;
+        writeln!(f, "Mean: {:.2}", self.mean)?;
+        writeln!(f, "Median: {:.1}", self.median)?;
+        let percentiles: Vec<String> = self
+            .percentiles
+            .iter()
+            .map(|(p, value)| format!("p{p} {value}"))
+            .collect();
+        writeln!(f, "Percentiles: {}", percentiles.join(", "))?;
+        writeln!(
+            f,
+            "Items: {} (from {} to {} per elf, median {:.1})",
+            self.items, self.fewest_items, self.most_items, self.median_items
+        )?;
+        writeln!(
+            f,
+            "Largest item: {} callories, carried by elf number {}",
+            self.largest_item.1, self.largest_item.0
+        )?;
+
+        writeln!(f, "Elf totals:")?;
+        let most = self.histogram.iter().map(|b| b.elves).max().unwrap_or(0);
+        let digits = self.histogram.last().map_or(1, |b| b.to.to_string().len());
+        for bucket in &self.histogram {
+            // Any non-empty range gets at least one character, so it doesn't look empty.
+            let bar = (bucket.elves * BAR_WIDTH).div_ceil(most.max(1));
+            writeln!(
+                f,
+                "{:>digits$} - {:>digits$} | {} {}",
+                bucket.from,
+                bucket.to,
+                "#".repeat(bar),
+                bucket.elves
+            )?;
+        }
+
+        Ok(())
+    }
+}
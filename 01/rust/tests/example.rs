@@ -0,0 +1,145 @@
+// Golden tests against the example input from the puzzle description.
+// Answers here are the ones given in the puzzle text, so they must never change.
+
+const EXAMPLE: &str = include_str!("../../test_input.txt");
+
+#[test]
+fn part_one_example() {
+    let input = day01::parse(EXAMPLE).unwrap();
+    assert_eq!(day01::part_one(&input), 24000);
+    assert_eq!(day01::max_calories(&input), Some((3, 24000)));
+}
+
+// Example doesn't end with a blank line, the last elf with 10000 calories still counts.
+#[test]
+fn part_two_example() {
+    let input = day01::parse(EXAMPLE).unwrap();
+    assert_eq!(day01::part_two(&input), 45000);
+}
+
+// Reading input line by line gives the same answers as parsing all of it.
+#[test]
+fn streaming_example() {
+    let reader = || EXAMPLE.as_bytes();
+    assert_eq!(day01::part_one_streaming(reader()).unwrap(), 24000);
+    assert_eq!(day01::part_two_streaming(reader()).unwrap(), 45000);
+}
+
+// Windows line endings, a byte order mark and extra blank lines don't change anything.
+#[test]
+fn messy_example() {
+    let messy = format!("\u{feff}\n{}\n\n\n", EXAMPLE.replace('\n', "\r\n"));
+    let input = day01::parse(&messy).unwrap();
+    assert_eq!(input, day01::parse(EXAMPLE).unwrap());
+    assert_eq!(day01::part_two_streaming(messy.as_bytes()).unwrap(), 45000);
+
+    let error = day01::parse("\u{feff}100\n1x0\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+}
+
+// Elves with the same total are ranked separately, the earlier one first, and all of them count.
+#[test]
+fn ties_in_ranking() {
+    let sums = vec![5000, 7000, 5000, 7000, 1000];
+    let ranked: Vec<(usize, u32)> = day01::top_elves(&sums, 4)
+        .iter()
+        .map(|elf| (elf.index, elf.calories))
+        .collect();
+    assert_eq!(ranked, vec![(1, 7000), (3, 7000), (0, 5000), (2, 5000)]);
+
+    assert_eq!(day01::max_calories(&sums), Some((1, 7000)));
+    assert_eq!(day01::part_two(&sums), 19000);
+    assert!(day01::top_elves(&sums, 0).is_empty());
+    assert_eq!(day01::top_elves(&sums, 10).len(), 5);
+
+    let file = "5000\n\n7000\n\n2000\n3000\n\n7000\n\n1000\n";
+    assert_eq!(day01::parse(file).unwrap(), sums);
+    let streamed = day01::top_elves_streaming(file.as_bytes(), 4).unwrap();
+    assert_eq!(streamed, day01::top_elves(&sums, 4));
+    assert_eq!(day01::part_two_streaming(file.as_bytes()).unwrap(), 19000);
+}
+
+#[test]
+fn ranking_example() {
+    let input = day01::parse(EXAMPLE).unwrap();
+    let top = day01::top_elves(&input, 3);
+    let indices: Vec<usize> = top.iter().map(|elf| elf.index).collect();
+    assert_eq!(indices, vec![3, 2, 4]);
+    assert_eq!(day01::total_calories(&top), 45000);
+}
+
+#[test]
+fn stats_example() {
+    let inventories = day01::stats::inventories(EXAMPLE).unwrap();
+    let stats = day01::stats::stats(&inventories).unwrap();
+
+    assert_eq!(stats.elves, 5);
+    assert_eq!(stats.total, 55000);
+    assert_eq!(stats.mean, 11000.0);
+    assert_eq!(stats.median, 10000.0);
+    assert_eq!(
+        stats.percentiles,
+        vec![
+            (10, 4000),
+            (25, 6000),
+            (50, 10000),
+            (75, 11000),
+            (90, 24000),
+            (99, 24000)
+        ]
+    );
+    assert_eq!(stats.items, 10);
+    assert_eq!((stats.fewest_items, stats.most_items), (1, 3));
+    assert_eq!(stats.median_items, 2.0);
+    assert_eq!(stats.largest_item, (4, 10000));
+
+    // Totals from 4000 to 24000 in ranges of 2001 calories.
+    let counts: Vec<usize> = stats.histogram.iter().map(|b| b.elves).collect();
+    assert_eq!(counts, vec![2, 0, 1, 1, 0, 0, 0, 0, 0, 1]);
+    assert_eq!(stats.histogram[0].from, 4000);
+    assert_eq!(stats.histogram[9].to, 24009);
+
+    assert!(day01::stats::stats(&[]).is_none());
+}
+
+// An elf carrying more than u32 can hold doesn't fit into part one, but statistics still work.
+#[test]
+fn stats_of_huge_elves() {
+    let file = "4000000000\n4000000000\n\n1\n";
+    let error = day01::parse(file).unwrap_err();
+    assert_eq!((error.line, error.column), (1, 1));
+    assert_eq!(
+        error.expected,
+        "elf carrying at most 4294967295 calories in total"
+    );
+
+    let stats = day01::stats::stats(&day01::stats::inventories(file).unwrap()).unwrap();
+    assert_eq!(stats.total, 8_000_000_001);
+    assert_eq!(stats.median, 4_000_000_000.5);
+    assert_eq!(stats.largest_item, (0, 4_000_000_000));
+    assert!(stats.to_string().contains("Total callories: 8000000001"));
+
+    // Single items are u64 too, only an elf total past that is an error.
+    let file = "5000000000\n\n1\n";
+    let stats = day01::stats::stats(&day01::stats::inventories(file).unwrap()).unwrap();
+    assert_eq!(stats.largest_item, (0, 5_000_000_000));
+    let error = day01::stats::inventories("18446744073709551615\n1\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(error.expected, "calories that keep elf total within u64");
+}
+
+// Each elf fits into u32, but their total doesn't have to.
+#[test]
+fn total_past_u32() {
+    let sums = vec![4_000_000_000, 4_000_000_000, 1];
+    let top = day01::top_elves(&sums, 2);
+    assert_eq!(day01::total_calories(&top), 8_000_000_000);
+    assert_eq!(day01::part_two(&sums), 8_000_000_001);
+
+    let file = "4000000000\n\n4000000000\n\n1\n";
+    assert_eq!(
+        day01::part_two_streaming(file.as_bytes()).unwrap(),
+        8_000_000_001
+    );
+    assert!(day01::ranking_report(&top).ends_with("top 2 elves: 8000000000\n"));
+}
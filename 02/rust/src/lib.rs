@@ -0,0 +1,279 @@
+// Day 02: Rock Paper Scissors.
+use common::stream::for_each_line;
+use common::{Answer, ParseError, Part, ReadError, Rng, Size, Solution};
+use std::io::BufRead;
+
+// Puzzle day number.
+pub const DAY: u8 = 2;
+
+// Enum that describes match result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Win,
+    Draw,
+    Lose,
+}
+
+// Trait for outcome to calculate result score.
+impl Outcome {
+    pub fn get_score(&self) -> u32 {
+        match &self {
+            Outcome::Win => 6,
+            Outcome::Draw => 3,
+            Outcome::Lose => 0,
+        }
+    }
+}
+
+// Possible Items that players can throw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Item {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Item {
+    // Value of each Item.
+    pub fn get_score(&self) -> u32 {
+        match &self {
+            Item::Rock => 1,
+            Item::Paper => 2,
+            Item::Scissors => 3,
+        }
+    }
+
+    // Returns an Item that we must use to get desired outcome using current Item.
+    pub fn get_item_from_outcome(&self, outcome: &Outcome) -> Self {
+        match (&self, &outcome) {
+            (Item::Rock, Outcome::Win) => Item::Paper,
+            (Item::Rock, Outcome::Lose) => Item::Scissors,
+            (Item::Paper, Outcome::Win) => Item::Scissors,
+            (Item::Paper, Outcome::Lose) => Item::Rock,
+            (Item::Scissors, Outcome::Win) => Item::Rock,
+            (Item::Scissors, Outcome::Lose) => Item::Paper,
+            (_, Outcome::Draw) => *self,
+        }
+    }
+}
+
+// Second column of the strategy guide. Part one reads it as an Item, part two as an Outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    X,
+    Y,
+    Z,
+}
+
+impl Hint {
+    // Item that player should throw.
+    pub fn to_item(self) -> Item {
+        match self {
+            Hint::X => Item::Rock,
+            Hint::Y => Item::Paper,
+            Hint::Z => Item::Scissors,
+        }
+    }
+
+    // Outcome that player must get.
+    pub fn to_outcome(self) -> Outcome {
+        match self {
+            Hint::X => Outcome::Lose,
+            Hint::Y => Outcome::Draw,
+            Hint::Z => Outcome::Win,
+        }
+    }
+}
+
+// Struct that holds each game Round.
+pub struct Round {
+    opponent: Item,
+    player: Item,
+}
+
+impl Round {
+    // Common Rust way to create structs.
+    pub fn new(opponent: Item, player: Item) -> Self {
+        Self { opponent, player }
+    }
+
+    // Calculate outcome of the Round.
+    pub fn get_outcome(&self) -> Outcome {
+        match (&self.player, &self.opponent) {
+            (Item::Rock, Item::Scissors) => Outcome::Win,
+            (Item::Rock, Item::Paper) => Outcome::Lose,
+            (Item::Paper, Item::Rock) => Outcome::Win,
+            (Item::Paper, Item::Scissors) => Outcome::Lose,
+            (Item::Scissors, Item::Paper) => Outcome::Win,
+            (Item::Scissors, Item::Rock) => Outcome::Lose,
+            _ => Outcome::Draw,
+        }
+    }
+
+    // Calculate score of the round.
+    pub fn calc_score(&self) -> u32 {
+        self.player.get_score() + self.get_outcome().get_score()
+    }
+}
+
+// Strategy guide: what opponent throws and a hint for each round.
+pub type Input = Vec<(Item, Hint)>;
+
+// Parse one line of strategy guide, with index i.
+fn parse_line(i: usize, line: &str) -> Result<(Item, Hint), ParseError> {
+    // Split a line in two values.
+    let mut iter = line.split_whitespace();
+    // Used to point at the end of line when value is missing.
+    let end = &line[line.len()..];
+
+    // First value is what current opponent is throwing.
+    let current_opponent = match iter.next() {
+        Some("A") => Item::Rock,
+        Some("B") => Item::Paper,
+        Some("C") => Item::Scissors,
+        v => {
+            return Err(ParseError::in_line(
+                DAY,
+                i,
+                line,
+                v.unwrap_or(end),
+                "A, B or C",
+            ))
+        }
+    };
+
+    // Second value is a hint of what to do.
+    let current_hint = match iter.next() {
+        Some("X") => Hint::X,
+        Some("Y") => Hint::Y,
+        Some("Z") => Hint::Z,
+        v => {
+            return Err(ParseError::in_line(
+                DAY,
+                i,
+                line,
+                v.unwrap_or(end),
+                "X, Y or Z",
+            ))
+        }
+    };
+
+    // Nothing else should be there.
+    if let Some(v) = iter.next() {
+        return Err(ParseError::in_line(DAY, i, line, v, "end of line"));
+    }
+
+    Ok((current_opponent, current_hint))
+}
+
+// Parse strategy guide.
+pub fn parse(file: &str) -> Result<Input, ParseError> {
+    // List of guide lines.
+    let mut guide: Input = Vec::new();
+
+    // For each line in the file.
+    for (i, line) in file.lines().enumerate() {
+        guide.push(parse_line(i, line)?);
+    }
+
+    Ok(guide)
+}
+
+// Score of a round when hint is an Item player throws.
+fn score_one(opponent: Item, hint: Hint) -> u32 {
+    Round::new(opponent, hint.to_item()).calc_score()
+}
+
+// Score of a round when hint is an Outcome player must get.
+fn score_two(opponent: Item, hint: Hint) -> u32 {
+    // Get player item with the outcome we need to fulfill.
+    let player = opponent.get_item_from_outcome(&hint.to_outcome());
+    Round::new(opponent, player).calc_score()
+}
+
+// Total score when second column is an Item player throws.
+// Totals are u64, generated guides can be big enough that a u32 one overflows.
+pub fn part_one(guide: &Input) -> u64 {
+    // Calculate a final sum by calling a score calculation of each round and summing those up.
+    guide
+        .iter()
+        .map(|(opponent, hint)| u64::from(score_one(*opponent, *hint)))
+        .sum()
+}
+
+// Total score when second column is an Outcome player must get.
+pub fn part_two(guide: &Input) -> u64 {
+    guide
+        .iter()
+        .map(|(opponent, hint)| u64::from(score_two(*opponent, *hint)))
+        .sum()
+}
+
+// Total score of the guide with a scoring of one round, reading input line by line.
+fn total_streaming(reader: impl BufRead, score: fn(Item, Hint) -> u32) -> Result<u64, ReadError> {
+    let mut total: u64 = 0;
+    for_each_line(reader, |i, line| {
+        let (opponent, hint) = parse_line(i, line)?;
+        total += u64::from(score(opponent, hint));
+        Ok(())
+    })?;
+
+    Ok(total)
+}
+
+// Same as part_one, but reads input line by line without keeping it.
+pub fn part_one_streaming(reader: impl BufRead) -> Result<u64, ReadError> {
+    total_streaming(reader, score_one)
+}
+
+// Same as part_two, but reads input line by line without keeping it.
+pub fn part_two_streaming(reader: impl BufRead) -> Result<u64, ReadError> {
+    total_streaming(reader, score_two)
+}
+
+// Random strategy guide with `size.amount` rounds.
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    (0..size.amount.max(1))
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.choose(&['A', 'B', 'C']),
+                rng.choose(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect()
+}
+
+// Solution of the day for the runner.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Rock Paper Scissors";
+    type Input = Input;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(file: &str) -> Result<Input, ParseError> {
+        parse(file)
+    }
+
+    fn part_one(input: &Input) -> u64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> u64 {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> String {
+        generate(rng, size)
+    }
+
+    fn stream(part: Part, reader: &mut dyn BufRead) -> Option<Result<Answer, ReadError>> {
+        Some(match part {
+            Part::One => part_one_streaming(reader).map(Answer::from),
+            Part::Two => part_two_streaming(reader).map(Answer::from),
+        })
+    }
+}
@@ -0,0 +1,236 @@
+// Day 03: Rucksack Reorganization.
+use common::stream::for_each_line;
+use common::{Answer, ParseError, Part, ReadError, Rng, Size, Solution};
+use std::collections::HashSet;
+use std::io::BufRead;
+
+// Puzzle day number.
+pub const DAY: u8 = 3;
+
+// Items of every rucksack.
+pub type Input = Vec<Vec<u8>>;
+
+// Parse one line with index i into items of a rucksack.
+fn parse_line(i: usize, line: &str) -> Result<Vec<u8>, ParseError> {
+    // Every item must be a letter, otherwise it has no priority.
+    if let Some((pos, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        let item = &line[pos..pos + c.len_utf8()];
+        return Err(ParseError::in_line(
+            DAY,
+            i,
+            line,
+            item,
+            "item letter a-z or A-Z",
+        ));
+    }
+
+    // Transform line to a list of bytes.
+    Ok(line.as_bytes().to_vec())
+}
+
+// Parse input into rucksacks.
+pub fn parse(file: &str) -> Result<Input, ParseError> {
+    let mut rucksacks: Input = Vec::new();
+
+    for (i, line) in file.lines().enumerate() {
+        rucksacks.push(parse_line(i, line)?);
+    }
+
+    Ok(rucksacks)
+}
+
+// Priority of an item.
+pub fn priority(item: u8) -> u32 {
+    if item > 96 {
+        u32::from(item) - 96
+    } else {
+        u32::from(item) - 38
+    }
+}
+
+// Priority of items that are in both compartments of rucksack.
+fn rucksack_priority(bytes: &[u8]) -> u32 {
+    // Split it by half in two slices.
+    let (first_half, second_half) = bytes.split_at(bytes.len() / 2);
+    // Create a Set out of the first half.
+    let mut a: HashSet<u8> = HashSet::new();
+    for v in first_half {
+        a.insert(*v);
+    }
+    // Create a Set out of the second half.
+    let mut b: HashSet<u8> = HashSet::new();
+    for v in second_half {
+        b.insert(*v);
+    }
+
+    // Find intersection of two sets, that's our common item. Calculate priority for each item and sum those.
+    a.intersection(&b).map(|v| priority(*v)).sum()
+}
+
+// Priority of the badge that is common for a group of 3 elves.
+fn badge_priority(group: &[Vec<u8>]) -> u32 {
+    // Create a Set out of the first member.
+    let mut a: HashSet<u8> = HashSet::new();
+    for v in &group[0] {
+        a.insert(*v);
+    }
+    // Create a Set out of the second member.
+    let mut b: HashSet<u8> = HashSet::new();
+    for v in &group[1] {
+        b.insert(*v);
+    }
+
+    // Create a Set out of the third member.
+    let mut c: HashSet<u8> = HashSet::new();
+    for v in &group[2] {
+        c.insert(*v);
+    }
+
+    // B&C intersection vector.
+    let b_c_intersection: Vec<&u8> = b.intersection(&c).collect();
+    // Create a Set out of the second and third intersection.
+    let mut bc: HashSet<u8> = HashSet::new();
+    for v in b_c_intersection {
+        bc.insert(*v);
+    }
+
+    // Find intersection of three sets, that's our common item.
+    a.intersection(&bc).map(|v| priority(*v)).sum()
+}
+
+// Sum of priorities of items that are in both compartments of rucksack.
+// Sum is u64: a u32 one is full after about 250 MB of "ZZ" lines, which generated inputs can reach.
+pub fn part_one(rucksacks: &Input) -> u64 {
+    rucksacks
+        .iter()
+        .map(|bytes| u64::from(rucksack_priority(bytes)))
+        .sum()
+}
+
+// Sum of priorities of badges that are common for each group of 3 elves.
+// Sum is u64 for the same reason as in part_one.
+pub fn part_two(rucksacks: &Input) -> u64 {
+    // Total result sum.
+    let mut total_sum: u64 = 0;
+
+    // For each group of three.
+    for chunk in rucksacks.chunks(3) {
+        // Group without 3 members has no badge.
+        if chunk.len() < 3 {
+            break;
+        }
+
+        total_sum += u64::from(badge_priority(chunk));
+    }
+
+    total_sum
+}
+
+// Same as part_one, but reads input line by line without keeping it.
+pub fn part_one_streaming(reader: impl BufRead) -> Result<u64, ReadError> {
+    let mut total_sum: u64 = 0;
+    for_each_line(reader, |i, line| {
+        total_sum += u64::from(rucksack_priority(&parse_line(i, line)?));
+        Ok(())
+    })?;
+
+    Ok(total_sum)
+}
+
+// Same as part_two, but reads input line by line and keeps only the current group.
+pub fn part_two_streaming(reader: impl BufRead) -> Result<u64, ReadError> {
+    let mut total_sum: u64 = 0;
+    let mut group: Vec<Vec<u8>> = Vec::with_capacity(3);
+    for_each_line(reader, |i, line| {
+        group.push(parse_line(i, line)?);
+        // Unfinished group at the end has no badge, just like in part_two.
+        if group.len() == 3 {
+            total_sum += u64::from(badge_priority(&group));
+            group.clear();
+        }
+        Ok(())
+    })?;
+
+    Ok(total_sum)
+}
+
+// Random input with `size.amount` rucksacks (rounded up to whole groups of three),
+// compartments hold from 2 to `size.width` items. Every rucksack has exactly one item type in both compartments,
+// every group has exactly one badge.
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let letters: Vec<u8> = (b'a'..=b'z').chain(b'A'..=b'Z').collect();
+    let mut file = String::new();
+
+    for _ in 0..size.amount.max(1).div_ceil(3) {
+        let mut others = letters.clone();
+        let badge = others.swap_remove(rng.index(others.len()));
+        rng.shuffle(&mut others);
+
+        // Each elf gets its own letters, so badge is the only one that all three have.
+        for pool in others.chunks(others.len() / 3).take(3) {
+            let mut pool = pool.to_vec();
+            // Item in both compartments, sometimes it's the badge itself.
+            let shared = if rng.one_in(10) {
+                badge
+            } else {
+                pool.swap_remove(rng.index(pool.len()))
+            };
+            let (left, right) = pool.split_at(pool.len() / 2);
+
+            let length = rng.range(2..=size.width.max(2) as u64) as usize;
+            let badge_left = rng.one_in(2);
+            let mut compartments = [vec![shared], vec![shared]];
+            for (compartment, fill, has_badge) in [(0, left, badge_left), (1, right, !badge_left)] {
+                let compartment = &mut compartments[compartment];
+                if has_badge && shared != badge {
+                    compartment.push(badge);
+                }
+                while compartment.len() < length {
+                    compartment.push(*rng.choose(fill));
+                }
+                rng.shuffle(compartment);
+            }
+
+            for compartment in compartments {
+                file.push_str(&String::from_utf8_lossy(&compartment));
+            }
+            file.push('\n');
+        }
+    }
+
+    file
+}
+
+// Solution of the day for the runner.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Rucksack Reorganization";
+    type Input = Input;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(file: &str) -> Result<Input, ParseError> {
+        parse(file)
+    }
+
+    fn part_one(input: &Input) -> u64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> u64 {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> String {
+        generate(rng, size)
+    }
+
+    fn stream(part: Part, reader: &mut dyn BufRead) -> Option<Result<Answer, ReadError>> {
+        Some(match part {
+            Part::One => part_one_streaming(reader).map(Answer::from),
+            Part::Two => part_two_streaming(reader).map(Answer::from),
+        })
+    }
+}
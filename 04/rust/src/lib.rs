@@ -0,0 +1,211 @@
+// Day 04: Camp Cleanup.
+use common::stream::for_each_line;
+use common::{Answer, ParseError, Part, ReadError, Rng, Size, Solution};
+use std::io::BufRead;
+use std::ops::RangeInclusive;
+
+// Puzzle day number.
+pub const DAY: u8 = 4;
+
+// Section ranges assigned to two elves.
+pub type Pair = (RangeInclusive<u32>, RangeInclusive<u32>);
+
+// Pairs of section ranges of every two elves.
+pub type Input = Vec<Pair>;
+
+// Parse one "start-end" section range, which is a part of line with line_index.
+fn parse_range(line_index: usize, line: &str, s: &str) -> Result<RangeInclusive<u32>, ParseError> {
+    let invalid =
+        |token: &str, expected: &str| ParseError::in_line(DAY, line_index, line, token, expected);
+    // Split each part into start and end indecies.
+    let (start, end) = s
+        .split_once('-')
+        .ok_or_else(|| invalid(s, "section range like 2-4"))?;
+    // Parse those indecies into numbers.
+    let start_section: u32 = start
+        .parse()
+        .map_err(|_| invalid(start, "section number"))?;
+    let end_section: u32 = end.parse().map_err(|_| invalid(end, "section number"))?;
+
+    Ok(start_section..=end_section)
+}
+
+// Parse one line with index i into a pair of section ranges.
+fn parse_line(i: usize, line: &str) -> Result<Pair, ParseError> {
+    // Split puzzle input into two parts separated by comma.
+    let (first_half, second_half) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::in_line(DAY, i, line, line, "pair like 2-4,6-8"))?;
+
+    Ok((
+        parse_range(i, line, first_half)?,
+        parse_range(i, line, second_half)?,
+    ))
+}
+
+// Parse input into pairs of section ranges.
+pub fn parse(file: &str) -> Result<Input, ParseError> {
+    let mut pairs: Input = Vec::new();
+
+    // For each new line.
+    for (i, line) in file.lines().enumerate() {
+        pairs.push(parse_line(i, line)?);
+    }
+
+    Ok(pairs)
+}
+
+// True if every section of `inner` is also in `outer`. Empty range (start after end) has no sections,
+// so it fits into any other range.
+fn contains(outer: &RangeInclusive<u32>, inner: &RangeInclusive<u32>) -> bool {
+    inner.is_empty() || (outer.start() <= inner.start() && inner.end() <= outer.end())
+}
+
+// True if ranges have at least one section in common.
+fn overlaps(a: &RangeInclusive<u32>, b: &RangeInclusive<u32>) -> bool {
+    !a.is_empty() && !b.is_empty() && a.start() <= b.end() && b.start() <= a.end()
+}
+
+// One range of the pair fully contains the other.
+fn either_contains((first, second): &Pair) -> bool {
+    // Only range bounds are compared, so huge ranges cost as much as small ones.
+    contains(first, second) || contains(second, first)
+}
+
+// Ranges of the pair overlap at all.
+fn pair_overlaps((first, second): &Pair) -> bool {
+    overlaps(first, second)
+}
+
+// Amount of pairs where one range fully contains the other.
+// Counts are u64 like totals of days 02 and 03, so streamed and parsed inputs give the same answer.
+pub fn part_one(pairs: &Input) -> u64 {
+    pairs.iter().filter(|pair| either_contains(pair)).count() as u64
+}
+
+// Amount of pairs where ranges overlap at all.
+pub fn part_two(pairs: &Input) -> u64 {
+    pairs.iter().filter(|pair| pair_overlaps(pair)).count() as u64
+}
+
+// Amount of pairs that pass `check`, reading input line by line.
+fn count_streaming(reader: impl BufRead, check: fn(&Pair) -> bool) -> Result<u64, ReadError> {
+    let mut count: u64 = 0;
+    for_each_line(reader, |i, line| {
+        if check(&parse_line(i, line)?) {
+            count += 1;
+        }
+        Ok(())
+    })?;
+
+    Ok(count)
+}
+
+// Same as part_one, but reads input line by line without keeping it.
+pub fn part_one_streaming(reader: impl BufRead) -> Result<u64, ReadError> {
+    count_streaming(reader, either_contains)
+}
+
+// Same as part_two, but reads input line by line without keeping it.
+pub fn part_two_streaming(reader: impl BufRead) -> Result<u64, ReadError> {
+    count_streaming(reader, pair_overlaps)
+}
+
+// First solution, with a HashSet of sections for every range. Memory grows with section numbers, but subset and
+// disjoint checks leave no room for off-by-one mistakes with range ends that `contains` and `overlaps` could make.
+pub mod reference {
+    use super::Input;
+    use std::collections::HashSet;
+    use std::ops::RangeInclusive;
+
+    // Create a Set out of the section range.
+    fn sections(range: &RangeInclusive<u32>) -> HashSet<u32> {
+        range.clone().collect()
+    }
+
+    // Amount of pairs where one range fully contains the other.
+    pub fn part_one(pairs: &Input) -> u64 {
+        // Total result sum.
+        let mut total_sum: u64 = 0;
+
+        for (first, second) in pairs {
+            let a = sections(first);
+            let b = sections(second);
+
+            // If first section range is subset or superset of second range, that means that we have overlap.
+            if a.is_subset(&b) || a.is_superset(&b) {
+                total_sum += 1;
+            }
+        }
+
+        total_sum
+    }
+
+    // Amount of pairs where ranges overlap at all.
+    pub fn part_two(pairs: &Input) -> u64 {
+        // Total result sum.
+        let mut total_sum: u64 = 0;
+
+        for (first, second) in pairs {
+            let a = sections(first);
+            let b = sections(second);
+
+            // If sets have any common value, we found an itersection.
+            if !a.is_disjoint(&b) {
+                total_sum += 1;
+            }
+        }
+
+        total_sum
+    }
+}
+
+// Random input with `size.amount` pairs of section ranges between 1 and `size.width`.
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let highest = size.width.max(1) as u64;
+    let range = |rng: &mut Rng| {
+        let (a, b) = (rng.range(1..=highest), rng.range(1..=highest));
+        format!("{}-{}", a.min(b), a.max(b))
+    };
+
+    (0..size.amount.max(1))
+        .map(|_| {
+            let first = range(rng);
+            format!("{first},{}\n", range(rng))
+        })
+        .collect()
+}
+
+// Solution of the day for the runner.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Camp Cleanup";
+    type Input = Input;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(file: &str) -> Result<Input, ParseError> {
+        parse(file)
+    }
+
+    fn part_one(input: &Input) -> u64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> u64 {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> String {
+        generate(rng, size)
+    }
+
+    fn stream(part: Part, reader: &mut dyn BufRead) -> Option<Result<Answer, ReadError>> {
+        Some(match part {
+            Part::One => part_one_streaming(reader).map(Answer::from),
+            Part::Two => part_two_streaming(reader).map(Answer::from),
+        })
+    }
+}
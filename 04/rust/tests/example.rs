@@ -0,0 +1,22 @@
+// Golden tests against the example input from the puzzle description.
+// Answers here are the ones given in the puzzle text, so they must never change.
+
+const EXAMPLE: &str = include_str!("../../test_input.txt");
+
+#[test]
+fn part_one_example() {
+    let input = day04::parse(EXAMPLE).unwrap();
+    assert_eq!(day04::part_one(&input), 2);
+}
+
+#[test]
+fn part_two_example() {
+    let input = day04::parse(EXAMPLE).unwrap();
+    assert_eq!(day04::part_two(&input), 4);
+}
+
+#[test]
+fn streaming_example() {
+    assert_eq!(day04::part_one_streaming(EXAMPLE.as_bytes()).unwrap(), 2);
+    assert_eq!(day04::part_two_streaming(EXAMPLE.as_bytes()).unwrap(), 4);
+}
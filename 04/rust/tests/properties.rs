@@ -0,0 +1,29 @@
+// Day 04 part_one and part_two only compare range ends, `reference` turns every range into a set of its sections
+// and asks sets instead. Up to 50 pairs with sections below 100, so the sets stay small. Ends are picked
+// independently, so about half of the ranges are reversed like 7-3: those have no sections at all, and end
+// comparisons must treat them just like empty sets do.
+use proptest::prelude::*;
+
+// Random input text with up to 50 pairs.
+fn input() -> impl Strategy<Value = String> {
+    prop::collection::vec((0u32..100, 0u32..100, 0u32..100, 0u32..100), 0..50).prop_map(|pairs| {
+        pairs
+            .iter()
+            .map(|(a, b, c, d)| format!("{a}-{b},{c}-{d}\n"))
+            .collect()
+    })
+}
+
+proptest! {
+    #[test]
+    fn part_one_matches_reference(file in input()) {
+        let input = day04::parse(&file).unwrap();
+        prop_assert_eq!(day04::part_one(&input), day04::reference::part_one(&input));
+    }
+
+    #[test]
+    fn part_two_matches_reference(file in input()) {
+        let input = day04::parse(&file).unwrap();
+        prop_assert_eq!(day04::part_two(&input), day04::reference::part_two(&input));
+    }
+}
@@ -0,0 +1,296 @@
+// Day 05: Supply Stacks.
+use common::error::finish;
+use common::records::{records, strip_bom};
+use common::{ParseError, Rng, Size, Solution};
+
+// Really wanted to learn this crate, now I have an excuse :)
+extern crate nom;
+use nom::{
+    branch::alt,
+    bytes::complete::is_a,
+    bytes::complete::tag,
+    character::complete::{char, line_ending},
+    multi::separated_list1,
+    sequence::{preceded, terminated},
+    IResult,
+};
+
+// Puzzle day number.
+pub const DAY: u8 = 5;
+
+// Struct to hold one move action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub amount: usize,
+    pub from_index: usize,
+    pub to_index: usize,
+}
+
+// Nom parser that matches [X] where X is an uppercase letter.
+fn crates_parser(input: &str) -> IResult<&str, char> {
+    let (input, c) = preceded(
+        char('['),
+        terminated(is_a("ABCDEFGHIJKLMNOPQRSTUVWXYZ"), char(']')),
+    )(input)?;
+
+    Ok((input, c.chars().next().unwrap()))
+}
+
+// Nom parser that matches "   " that means it's an empty cargo there.
+fn empty_cargo_parser(input: &str) -> IResult<&str, char> {
+    let (input, _) = tag("   ")(input)?;
+
+    Ok((input, ' '))
+}
+
+// Nom parser to parse just one line of setup string.
+fn setup_line_parser(input: &str) -> IResult<&str, Vec<char>> {
+    separated_list1(tag(" "), alt((crates_parser, empty_cargo_parser)))(input)
+}
+
+// Nom parser to parse multiple lines of setup string. Lines may end with "\n" or "\r\n".
+fn setup_lines_parser(input: &str) -> IResult<&str, Vec<Vec<char>>> {
+    separated_list1(line_ending, setup_line_parser)(input)
+}
+
+// Nom parser to parse last line of setup string.
+fn crate_numbers_line_parser(input: &str) -> IResult<&str, Vec<u32>> {
+    separated_list1(tag("   "), nom::character::complete::u32)(input)
+}
+
+// Nom parser that parses line of move string.
+fn move_line_parser(input: &str) -> IResult<&str, Move> {
+    let (input, amount) = preceded(tag("move "), nom::character::complete::u32)(input)?;
+    let (input, from_index) = preceded(tag(" from "), nom::character::complete::u32)(input)?;
+    let (input, to_index) = preceded(tag(" to "), nom::character::complete::u32)(input)?;
+
+    Ok((
+        input,
+        Move {
+            amount: amount as usize,
+            from_index: from_index as usize,
+            to_index: to_index as usize,
+        },
+    ))
+}
+
+// Starting stacks of crates and moves that crane has to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub stacks: Vec<Vec<char>>,
+    pub moves: Vec<Move>,
+}
+
+// Parse input into starting situation and moves we have to do.
+pub fn parse(file: &str) -> Result<Input, ParseError> {
+    // Setup is the first record, moves come after a blank line. Both are parts of the file, so errors point into it.
+    let file = strip_bom(file);
+    let mut records = records(file);
+    let setup = records
+        .next()
+        .ok_or_else(|| ParseError::at_end(DAY, file, "crates like [A]"))?;
+
+    // Use Nom to parse setup crates positions into char vector.
+    let (left, crates) = setup_lines_parser(setup.text)
+        .map_err(|e| ParseError::from_nom(DAY, file, e, "crates like [A]"))?;
+    // Use Nom to parse last line of initial crates positions into a vector of crates numbers.
+    // Parser stops at the first line that is not crates, so if something is wrong it's reported here.
+    let mut crate_numbers = finish(
+        DAY,
+        file,
+        crate_numbers_line_parser(left.trim()),
+        "crates like [A] or stack numbers like 1   2   3",
+    )?;
+    // Stacks are numbered from 1 in order, so the last number is amount of stacks.
+    if crate_numbers
+        .iter()
+        .zip(1..)
+        .any(|(n, expected)| *n != expected)
+    {
+        let numbers = left.trim_start();
+        let expected = "stack numbers counting from 1 like 1   2   3";
+        return Err(ParseError::at(DAY, file, numbers, expected));
+    }
+    // Use crate_numbers vector to get total crates number.
+    let total_crates_number = crate_numbers.pop().unwrap_or(0) as usize;
+
+    // Initialization of list that holds all each stacks of crates.
+    let mut stacks: Vec<Vec<char>> = vec![Vec::new(); total_crates_number];
+
+    // Go through each parsed crates list and append it into our stacks vector.
+    for r in crates.iter().rev() {
+        for (i, c) in r.iter().enumerate() {
+            if *c != ' ' {
+                stacks
+                    .get_mut(i)
+                    .ok_or_else(|| ParseError::at(DAY, file, left, "stack number for every crate"))?
+                    .push(*c);
+            }
+        }
+    }
+
+    // Amount of crates on each stack while moves are made, crane can't take more than there is.
+    let mut heights: Vec<usize> = stacks.iter().map(|stack| stack.len()).collect();
+    let mut moves: Vec<Move> = Vec::new();
+
+    // Every line after the setup is a move, blank lines between them don't matter.
+    for (i, line) in records.flat_map(|record| record.lines()) {
+        // Use nom to parse a Move.
+        let mov = finish(
+            DAY,
+            file,
+            move_line_parser(line),
+            "move like \"move 1 from 2 to 3\"",
+        )?;
+
+        // Each move must be between existing stacks.
+        // Line looks like "move 1 from 2 to 3", split it to point at wrong stack number.
+        let words: Vec<&str> = line.split(' ').collect();
+        for (index, word) in [(mov.from_index, words[3]), (mov.to_index, words[5])] {
+            if index == 0 || index > stacks.len() {
+                let expected = format!("stack number from 1 to {}", stacks.len());
+                return Err(ParseError::in_line(DAY, i, line, word, expected));
+            }
+        }
+
+        let available = heights[mov.from_index - 1];
+        if mov.amount > available {
+            let expected = format!("at most {available} crates, that is all stack has by then");
+            return Err(ParseError::in_line(DAY, i, line, words[1], expected));
+        }
+        heights[mov.from_index - 1] -= mov.amount;
+        heights[mov.to_index - 1] += mov.amount;
+
+        moves.push(mov);
+    }
+
+    if moves.is_empty() {
+        return Err(ParseError::at_end(
+            DAY,
+            file,
+            "move like \"move 1 from 2 to 3\"",
+        ));
+    }
+
+    Ok(Input { stacks, moves })
+}
+
+// Crates that end up on top of each stack.
+fn top_crates(stacks: &[Vec<char>]) -> String {
+    stacks.iter().filter_map(|stack| stack.last()).collect()
+}
+
+// Top crates after CrateMover 9000 moves crates one at a time.
+pub fn part_one(input: &Input) -> String {
+    let mut stacks = input.stacks.clone();
+
+    // For each move in parsed moves.
+    for mov in &input.moves {
+        // Get amount of moves we have to do. And for each of them.
+        for _ in 0..mov.amount {
+            // Pop a top value from required stack.
+            let v = stacks.get_mut(mov.from_index - 1).unwrap().pop().unwrap();
+            // And push it into anouther required stack.
+            stacks.get_mut(mov.to_index - 1).unwrap().push(v);
+        }
+    }
+
+    top_crates(&stacks)
+}
+
+// Top crates after CrateMover 9001 moves multiple crates at once.
+pub fn part_two(input: &Input) -> String {
+    let mut stacks = input.stacks.clone();
+
+    // For each move in parsed moves.
+    for mov in &input.moves {
+        // Get stack that we will pop values from.
+        let from_stack = stacks.get_mut(mov.from_index - 1).unwrap();
+        // Pop mov.amount of value from top of the stack keeping the order.
+        let mut poped_values: Vec<char> = from_stack
+            .drain((from_stack.len() - mov.amount)..)
+            .collect();
+        // Get stack that we will push to.
+        let to_stack = stacks.get_mut(mov.to_index - 1).unwrap();
+        // Append popped values to the top of the stack.
+        to_stack.append(&mut poped_values);
+    }
+
+    top_crates(&stacks)
+}
+
+// Random crane setup with `size.width` stacks (from 2 to 9) of up to `size.height` crates,
+// followed by `size.amount` moves. Moves never take more crates than a stack has at that moment.
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let stacks_amount = size.width.clamp(2, 9);
+    let mut stacks: Vec<Vec<char>> = (0..stacks_amount)
+        .map(|_| {
+            let height = rng.range(1..=size.height.max(1) as u64);
+            (0..height)
+                .map(|_| char::from(b'A' + rng.below(26) as u8))
+                .collect()
+        })
+        .collect();
+
+    // Setup is drawn from the top row down, stack numbers go under it.
+    let mut file = String::new();
+    let highest = stacks.iter().map(|s| s.len()).max().unwrap_or(0);
+    for row in (0..highest).rev() {
+        let line: Vec<String> = stacks
+            .iter()
+            .map(|s| match s.get(row) {
+                Some(c) => format!("[{c}]"),
+                None => "   ".to_string(),
+            })
+            .collect();
+        file += &line.join(" ");
+        file.push('\n');
+    }
+    let numbers: Vec<String> = (1..=stacks_amount).map(|n| format!(" {n} ")).collect();
+    file += &numbers.join(" ");
+    file.push_str("\n\n");
+
+    for _ in 0..size.amount.max(1) {
+        // Pick a stack that has something to move, there is always one as crates never disappear.
+        let non_empty: Vec<usize> = (0..stacks_amount)
+            .filter(|i| !stacks[*i].is_empty())
+            .collect();
+        let from = *rng.choose(&non_empty);
+        let to = (from + 1 + rng.index(stacks_amount - 1)) % stacks_amount;
+        let amount = rng.range(1..=stacks[from].len() as u64) as usize;
+
+        let left = stacks[from].len() - amount;
+        let moved: Vec<char> = stacks[from].drain(left..).collect();
+        stacks[to].extend(moved);
+        file += &format!("move {amount} from {} to {}\n", from + 1, to + 1);
+    }
+
+    file
+}
+
+// Solution of the day for the runner.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Supply Stacks";
+    type Input = Input;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(file: &str) -> Result<Input, ParseError> {
+        parse(file)
+    }
+
+    fn part_one(input: &Input) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> String {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> String {
+        generate(rng, size)
+    }
+}
@@ -0,0 +1,46 @@
+// Golden tests against the example input from the puzzle description.
+// Answers here are the ones given in the puzzle text, so they must never change.
+
+const EXAMPLE: &str = include_str!("../../test_input.txt");
+
+#[test]
+fn part_one_example() {
+    let input = day05::parse(EXAMPLE).unwrap();
+    assert_eq!(day05::part_one(&input), "CMZ");
+}
+
+#[test]
+fn part_two_example() {
+    let input = day05::parse(EXAMPLE).unwrap();
+    assert_eq!(day05::part_two(&input), "MCD");
+}
+
+// Crane can't take more crates than a stack has at that moment, such input is refused instead of panicking.
+#[test]
+fn moves_of_missing_crates_are_rejected() {
+    let file = EXAMPLE.replace("move 3 from 1 to 3", "move 4 from 1 to 3");
+    let error = day05::parse(&file).unwrap_err();
+    assert_eq!((error.line, error.column), (7, 6));
+    assert_eq!(
+        error.expected,
+        "at most 3 crates, that is all stack has by then"
+    );
+}
+
+// Stack numbers tell how many stacks there are, so they must count up from 1.
+#[test]
+fn stack_numbers_must_count_from_one() {
+    let error = day05::parse(&EXAMPLE.replace(" 1   2   3", " 1   2   9")).unwrap_err();
+    assert_eq!((error.line, error.column), (4, 2));
+}
+
+// Windows line endings, a byte order mark and extra blank lines don't change anything.
+#[test]
+fn messy_example() {
+    let messy = format!("\u{feff}{}\r\n\r\n", EXAMPLE.replace('\n', "\r\n"));
+    let input = day05::parse(&messy).unwrap();
+    assert_eq!(input, day05::parse(EXAMPLE).unwrap());
+
+    let spaced = EXAMPLE.replace("move 3 from 1 to 3\n", "move 3 from 1 to 3\n\n \n");
+    assert_eq!(day05::parse(&spaced).unwrap(), input);
+}
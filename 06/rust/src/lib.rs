@@ -0,0 +1,253 @@
+// Day 06: Tuning Trouble.
+use common::{Answer, ParseError, Part, ReadError, Rng, Size, Solution};
+use std::io::BufRead;
+
+// Puzzle day number.
+pub const DAY: u8 = 6;
+
+// Characters of datastream buffer.
+pub type Input = Vec<char>;
+
+// Parse input into datastream characters.
+pub fn parse(file: &str) -> Result<Input, ParseError> {
+    let datastream = file.trim_end();
+
+    // Datastream is one line of lowercase letters.
+    if let Some((pos, c)) = datastream
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_lowercase())
+    {
+        let rest = &datastream[pos..pos + c.len_utf8()];
+        return Err(ParseError::at(DAY, file, rest, "datastream letter a-z"));
+    }
+
+    // Collect all file characters into a vector.
+    Ok(datastream.chars().collect())
+}
+
+// Finds where the last `length` letters are all different, taking one letter at a time.
+// Letters must be a-z, which parse makes sure of.
+struct MarkerFinder {
+    length: usize,
+    // Position where each letter was seen last time.
+    last_seen: [Option<usize>; 26],
+    // First position of the window where all letters are different.
+    window_start: usize,
+    // Letters taken so far.
+    processed: usize,
+}
+
+impl MarkerFinder {
+    fn new(length: usize) -> Self {
+        Self {
+            length,
+            last_seen: [None; 26],
+            window_start: 0,
+            processed: 0,
+        }
+    }
+
+    // Take the next letter. Gives amount of letters processed once the marker is complete.
+    fn push(&mut self, letter: u8) -> Option<usize> {
+        let i = self.processed;
+        self.processed += 1;
+
+        let index = (letter - b'a') as usize;
+        // Window can't hold this letter twice, so it has to start after its previous appearance.
+        if let Some(seen) = self.last_seen[index] {
+            self.window_start = self.window_start.max(seen + 1);
+        }
+        self.last_seen[index] = Some(i);
+
+        (self.processed - self.window_start == self.length).then_some(self.processed)
+    }
+}
+
+// Characters processed until the last `length` characters are all different.
+fn first_marker(c: &[char], length: usize) -> Option<usize> {
+    let mut finder = MarkerFinder::new(length);
+    c.iter().find_map(|letter| finder.push(*letter as u8))
+}
+
+// Characters processed before start-of-packet marker is found.
+pub fn part_one(c: &Input) -> Option<usize> {
+    first_marker(c, 4)
+}
+
+// Characters processed before start-of-message marker is found.
+pub fn part_two(c: &Input) -> Option<usize> {
+    first_marker(c, 14)
+}
+
+// Line and column (both from 1) of a byte in the stream, with the character found there.
+type Position = (usize, usize, String);
+
+// What is found at a byte, for errors. Only letters are expected, so anything outside of ASCII is just named.
+fn found_at(byte: u8) -> String {
+    if byte == b'\n' {
+        "end of line".to_string()
+    } else if byte.is_ascii() {
+        format!("{:?}", char::from(byte).to_string())
+    } else {
+        "non-ASCII character".to_string()
+    }
+}
+
+// Same as first_marker, but reads datastream bit by bit straight from the reader, so it doesn't have to fit into
+// memory or even be one line. Whole datastream is still checked, like parse does.
+fn first_marker_streaming(
+    mut reader: impl BufRead,
+    length: usize,
+) -> Result<Option<usize>, ReadError> {
+    let mut finder = MarkerFinder::new(length);
+    let mut marker = None;
+    // Position of the next byte.
+    let (mut line, mut column) = (1, 1);
+    // Whitespace is allowed only at the very end, remember where it started.
+    let mut whitespace: Option<Position> = None;
+
+    loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            break;
+        }
+
+        for &byte in buffer {
+            if byte.is_ascii_lowercase() && whitespace.is_none() {
+                if marker.is_none() {
+                    marker = finder.push(byte);
+                }
+            } else if byte.is_ascii_whitespace() {
+                whitespace.get_or_insert((line, column, found_at(byte)));
+            } else {
+                // Letter after whitespace, or something that is not a letter at all.
+                let (line, column, found) = whitespace.unwrap_or((line, column, found_at(byte)));
+                let error = ParseError::new(DAY, line, column, "datastream letter a-z", found);
+                return Err(error.into());
+            }
+
+            // Columns count characters, so bytes that continue a multi-byte character are skipped.
+            if byte == b'\n' {
+                (line, column) = (line + 1, 1);
+            } else if byte & 0b1100_0000 != 0b1000_0000 {
+                column += 1;
+            }
+        }
+
+        let read = buffer.len();
+        reader.consume(read);
+    }
+
+    Ok(marker)
+}
+
+// Same as part_one, but reads input bit by bit without keeping it.
+pub fn part_one_streaming(reader: impl BufRead) -> Result<Option<usize>, ReadError> {
+    first_marker_streaming(reader, 4)
+}
+
+// Same as part_two, but reads input bit by bit without keeping it.
+pub fn part_two_streaming(reader: impl BufRead) -> Result<Option<usize>, ReadError> {
+    first_marker_streaming(reader, 14)
+}
+
+// First solution, comparing every pair of characters in every window: 6 comparisons per window of the packet
+// marker, 91 of the message one. It does the same work again for every window, but has no table to keep in sync.
+pub mod reference {
+    use super::Input;
+
+    // Characters processed before start-of-packet marker is found.
+    pub fn part_one(c: &Input) -> Option<usize> {
+        // Get sliding window of those characters.
+        let sliding_window = c.windows(4);
+        // Enumerate those.
+        for (i, window) in sliding_window.enumerate() {
+            // If any character matches any other character.
+            if window[0] != window[1]
+                && window[0] != window[2]
+                && window[0] != window[3]
+                && window[1] != window[2]
+                && window[1] != window[3]
+                && window[2] != window[3]
+            {
+                // We have found sequence!
+                return Some(i + 4);
+            }
+        }
+
+        None
+    }
+
+    // Characters processed before start-of-message marker is found.
+    pub fn part_two(c: &Input) -> Option<usize> {
+        // Get sliding window of those characters.
+        let sliding_window = c.windows(14);
+        // Enumerate those.
+        'outer: for (i, window) in sliding_window.enumerate() {
+            // For each other value.
+            for k in 0..14 {
+                for j in (1 + k)..14 {
+                    // Check if they are the same and go to the next window if they are.
+                    if window[k] == window[j] {
+                        continue 'outer;
+                    }
+                }
+            }
+
+            // We end up here only if all numbers are different in a window.
+            return Some(i + 14);
+        }
+
+        None
+    }
+}
+
+// Random datastream of `size.amount` characters (at least 14). Everything before the last 14 characters
+// uses only three letters, so both markers are as far as they can be.
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let length = size.amount.max(14);
+    let mut datastream: String = (0..length - 14)
+        .map(|_| *rng.choose(&['a', 'b', 'c']))
+        .collect();
+
+    let mut marker: Vec<char> = ('a'..='z').collect();
+    rng.shuffle(&mut marker);
+    datastream.extend(&marker[..14]);
+    datastream.push('\n');
+
+    datastream
+}
+
+// Solution of the day for the runner.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Tuning Trouble";
+    type Input = Input;
+    type Answer1 = Option<usize>;
+    type Answer2 = Option<usize>;
+
+    fn parse(file: &str) -> Result<Input, ParseError> {
+        parse(file)
+    }
+
+    fn part_one(input: &Input) -> Option<usize> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> Option<usize> {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> String {
+        generate(rng, size)
+    }
+
+    fn stream(part: Part, reader: &mut dyn BufRead) -> Option<Result<Answer, ReadError>> {
+        Some(match part {
+            Part::One => part_one_streaming(reader).map(Answer::from),
+            Part::Two => part_two_streaming(reader).map(Answer::from),
+        })
+    }
+}
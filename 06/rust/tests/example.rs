@@ -0,0 +1,62 @@
+// Golden tests against the example input from the puzzle description.
+// Answers here are the ones given in the puzzle text, so they must never change.
+
+const EXAMPLE: &str = include_str!("../../test_input.txt");
+
+#[test]
+fn part_one_example() {
+    let input = day06::parse(EXAMPLE).unwrap();
+    assert_eq!(day06::part_one(&input), Some(7));
+}
+
+#[test]
+fn part_two_example() {
+    let input = day06::parse(EXAMPLE).unwrap();
+    assert_eq!(day06::part_two(&input), Some(19));
+}
+
+// Other examples from the puzzle description, with answers for both parts.
+const MORE_EXAMPLES: [(&str, usize, usize); 4] = [
+    ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+    ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+    ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+    ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+];
+
+#[test]
+fn more_examples() {
+    for (example, one, two) in MORE_EXAMPLES {
+        let input = day06::parse(example).unwrap();
+        assert_eq!(day06::part_one(&input), Some(one), "{example}");
+        assert_eq!(day06::part_two(&input), Some(two), "{example}");
+    }
+}
+
+#[test]
+fn streaming_examples() {
+    let all = [(EXAMPLE, 7, 19)].into_iter().chain(MORE_EXAMPLES);
+    for (example, one, two) in all {
+        let one_streamed = day06::part_one_streaming(example.as_bytes()).unwrap();
+        let two_streamed = day06::part_two_streaming(example.as_bytes()).unwrap();
+        assert_eq!(one_streamed, Some(one), "{example}");
+        assert_eq!(two_streamed, Some(two), "{example}");
+    }
+}
+
+// Datastream read bit by bit is checked just like a parsed one, errors point to the same place.
+#[test]
+fn streaming_errors() {
+    for bad in ["abcd efgh", "abc\ndef", "abcD", "ab\n\ncd\n", "abcé"] {
+        let parsed = day06::parse(bad).unwrap_err();
+        match day06::part_one_streaming(bad.as_bytes()) {
+            Err(common::ReadError::Parse(e)) => {
+                assert_eq!((e.line, e.column), (parsed.line, parsed.column), "{bad:?}")
+            }
+            other => panic!("{bad:?} gave {other:?}"),
+        }
+    }
+    assert_eq!(
+        day06::part_one_streaming("abcd \n\n".as_bytes()).unwrap(),
+        Some(4)
+    );
+}
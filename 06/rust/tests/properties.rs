@@ -0,0 +1,30 @@
+// Day 06 part_one and part_two find markers with a table of where each letter was last seen, `reference` compares
+// every pair of characters in every window. Datastreams can also be empty or shorter than a marker, where both
+// must give None.
+use proptest::prelude::*;
+
+// Random datastreams of four kinds. 3 letters never have any marker. 3 letters followed by "abcd" always have
+// a 4 letter marker, and it ends right at that "d": no window before it holds 4 different letters. 16 letters over
+// 400 characters find both markers late, after many near misses, and all 26 letters find them early.
+fn input() -> impl Strategy<Value = String> {
+    prop_oneof![
+        "[a-c]{0,60}",
+        "[a-c]{0,30}abcd[a-d]{0,30}",
+        "[a-p]{0,400}",
+        "[a-z]{0,60}"
+    ]
+}
+
+proptest! {
+    #[test]
+    fn part_one_matches_reference(file in input()) {
+        let input = day06::parse(&file).unwrap();
+        prop_assert_eq!(day06::part_one(&input), day06::reference::part_one(&input));
+    }
+
+    #[test]
+    fn part_two_matches_reference(file in input()) {
+        let input = day06::parse(&file).unwrap();
+        prop_assert_eq!(day06::part_two(&input), day06::reference::part_two(&input));
+    }
+}
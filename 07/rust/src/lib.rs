@@ -0,0 +1,342 @@
+// Day 07: No Space Left On Device.
+// This solution is meh. It's 1 AM of the next day for me already... Should have done more iterators...
+use common::error::finish;
+use common::{ParseError, Rng, Size, Solution};
+
+// As I want to get better with Nom I will keep using it.
+use nom::{
+    branch::alt,
+    bytes::complete::is_a,
+    bytes::complete::tag,
+    character::complete::{alpha1, newline},
+    multi::separated_list1,
+    sequence::preceded,
+    sequence::separated_pair,
+    IResult,
+};
+use std::collections::HashMap;
+
+// Puzzle day number.
+pub const DAY: u8 = 7;
+
+// Enum to hold Terminal Output. Nom parsers will output it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TerminalOutput {
+    LFile(u64),
+    LDir(String),
+    Cd(String),
+    Ls,
+}
+
+// Different Nom parsers bellow. If interested check other solutions I have.
+fn parse_cd(i: &str) -> IResult<&str, TerminalOutput> {
+    let (i, res) = preceded(tag("$ cd "), alt((alpha1, is_a("../"))))(i)?;
+
+    Ok((i, TerminalOutput::Cd(res.to_string())))
+}
+
+fn parse_ls(i: &str) -> IResult<&str, TerminalOutput> {
+    let (i, _res) = tag("$ ls")(i)?;
+
+    Ok((i, TerminalOutput::Ls))
+}
+
+fn parse_command(i: &str) -> IResult<&str, TerminalOutput> {
+    let (i, res) = alt((parse_cd, parse_ls))(i)?;
+
+    Ok((i, res))
+}
+
+fn parse_lfile(i: &str) -> IResult<&str, TerminalOutput> {
+    let (i, res) = separated_pair(
+        nom::character::complete::u64,
+        tag(" "),
+        is_a("abcdefghijklmnopqrstuvwxyz."),
+    )(i)?;
+
+    Ok((i, TerminalOutput::LFile(res.0)))
+}
+
+fn parse_ldir(i: &str) -> IResult<&str, TerminalOutput> {
+    let (i, res) = preceded(tag("dir "), alpha1)(i)?;
+
+    Ok((i, TerminalOutput::LDir(res.to_string())))
+}
+
+fn parse_listed(i: &str) -> IResult<&str, TerminalOutput> {
+    let (i, res) = alt((parse_lfile, parse_ldir))(i)?;
+
+    Ok((i, res))
+}
+
+fn parse_line(i: &str) -> IResult<&str, TerminalOutput> {
+    let (i, res) = alt((parse_command, parse_listed))(i)?;
+
+    Ok((i, res))
+}
+
+fn parse_lines(i: &str) -> IResult<&str, Vec<TerminalOutput>> {
+    separated_list1(newline, parse_line)(i)
+}
+
+// Struct to hold a directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MyDir {
+    pub size: u64,                    // Size of directory.
+    pub files: Vec<u64>,              // List of file sizes. (As we don't need names.)
+    pub dirs: HashMap<String, MyDir>, // HashMap with the name and nested directory itself.
+}
+
+// For this struct.
+impl MyDir {
+    // Implement new function, that initializes it.
+    pub fn new() -> Self {
+        Self {
+            size: 0,
+            files: Vec::new(),
+            dirs: HashMap::new(),
+        }
+    }
+
+    // Returns a sum of file sizes that are stored in this directory, None if it doesn't fit into u64.
+    pub fn get_files_size(&self) -> Option<u64> {
+        self.files
+            .iter()
+            .try_fold(0u64, |sum, size| sum.checked_add(*size))
+    }
+
+    // Travels through each nested directory and calculates it's size, None if some size doesn't fit into u64.
+    pub fn calc_total_size(&mut self) -> Option<u64> {
+        // Get size of files inside this directory.
+        let mut total_sum = self.get_files_size()?;
+
+        // Get all directories that are nested inside of this directory.
+        let dirs: Vec<&mut MyDir> = self.dirs.values_mut().collect();
+        // For each one of them.
+        for dir in dirs {
+            // Recursively call this function and add this to a current total sum.
+            total_sum = total_sum.checked_add(dir.calc_total_size()?)?;
+        }
+
+        // Safe calculated sum.
+        self.size = total_sum;
+        // Return it.
+        Some(total_sum)
+    }
+
+    // Returns a list of nested directories sizes.
+    pub fn get_total_sizes(&self) -> Vec<u64> {
+        // Create a vector and push this directory size into it.
+        let mut sizes: Vec<u64> = vec![self.size];
+
+        // For each directory that is nested inside of this directory.
+        for dir in self.dirs.values() {
+            // Recursively call this function and append this to a current vector of sizes.
+            sizes.append(&mut dir.get_total_sizes());
+        }
+
+        // Return this vector.
+        sizes
+    }
+}
+
+// Root of the file tree with sizes of all directories calculated.
+pub type Input = MyDir;
+
+// Parse terminal output into a file tree.
+pub fn parse(file: &str) -> Result<Input, ParseError> {
+    // Parse file into a List of TerminalOutput enums.
+    let res = finish(
+        DAY,
+        file,
+        parse_lines(file),
+        "command like \"$ cd a\" or listing like \"dir a\"",
+    )?;
+
+    // Initialize root directory.
+    let mut root = MyDir::new();
+    root.dirs.insert("/".to_string(), MyDir::new());
+
+    // This a Stack that will hold current path that we are in.
+    let mut current_path: Vec<String> = Vec::new();
+
+    // For each terminal output, match it with specific enum instance.
+    for (i, output) in res.into_iter().enumerate() {
+        match output {
+            // If we have a 'cd' command.
+            TerminalOutput::Cd(path) => {
+                // Push current cd path into current_path stack, unless it's a '..' path.
+                // In that case we pop a path.
+                if path == ".." {
+                    current_path.pop();
+                } else {
+                    current_path.push(path);
+                }
+            }
+            TerminalOutput::Ls => (),
+            // If we have a file entry.
+            TerminalOutput::LFile(size) => {
+                // Go through each directory in our path stack starting from root.
+                let mut current_dir: &mut MyDir = &mut root;
+                for path in &current_path {
+                    current_dir = current_dir.dirs.get_mut(path).ok_or_else(|| {
+                        let line = file.lines().nth(i).unwrap_or("");
+                        ParseError::in_line(
+                            DAY,
+                            i,
+                            line,
+                            line,
+                            format!("{path:?} to be listed first"),
+                        )
+                    })?;
+                }
+
+                // Last directory we went through is the one where we want to add new files into.
+                current_dir.files.push(size);
+            }
+            // If we have a directory entry.
+            TerminalOutput::LDir(name) => {
+                // Go through each directory in our path stack starting from root.
+                let mut current_dir: &mut MyDir = &mut root;
+                for path in &current_path {
+                    current_dir = current_dir.dirs.get_mut(path).ok_or_else(|| {
+                        let line = file.lines().nth(i).unwrap_or("");
+                        ParseError::in_line(
+                            DAY,
+                            i,
+                            line,
+                            line,
+                            format!("{path:?} to be listed first"),
+                        )
+                    })?;
+                }
+
+                // Last directory we went through is the one where we want to add new directory into.
+                current_dir.dirs.insert(name, MyDir::new());
+            }
+        }
+    }
+
+    // At this point we have finished creating a file tree.
+    // Now we can go and calculate sizes of all directories inside of it. Directory listed again starts empty,
+    // so only the final tree tells whether its sizes fit into u64.
+    root.calc_total_size()
+        .ok_or_else(|| ParseError::at_end(DAY, file, "directory sizes that fit into u64"))?;
+
+    Ok(root)
+}
+
+// Sum of all directories sizes that are at most 100000.
+pub fn part_one(root: &Input) -> u64 {
+    // Get a List of all sizes and filter those to the challange requirement.
+    root.get_total_sizes()
+        .into_iter()
+        .filter(|x| *x <= 100000)
+        .sum()
+}
+
+// Size of smallest directory that frees enough space for the update.
+pub fn part_two(root: &Input) -> u64 {
+    // Calculate space the is required to be freed.
+    let used = root.dirs.get("/").map(|d| d.size).unwrap_or(root.size);
+    let space_required = 30000000u64.saturating_sub(70000000u64.saturating_sub(used));
+
+    // Find directories that are greater than that number above and take smallest one out of them.
+    root.get_total_sizes()
+        .into_iter()
+        .filter(|x| *x >= space_required)
+        .min()
+        .unwrap_or(0)
+}
+
+// Random terminal output that explores `size.amount` directories nested in random ways,
+// each with up to `size.width` files. Every directory is listed once and left with "cd .." when it's done.
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    // Children of every directory, root is 0. Each directory is put inside of a random earlier one.
+    let amount = size.amount.max(1);
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); amount + 1];
+    for dir in 1..=amount {
+        children[rng.index(dir)].push(dir);
+    }
+
+    // Names only have to be unique inside of one directory, but unique everywhere is simpler.
+    let name = |mut n: usize| {
+        let mut name = String::new();
+        loop {
+            name.insert(0, char::from(b'a' + (n % 26) as u8));
+            n /= 26;
+            if n == 0 {
+                return name;
+            }
+            n -= 1;
+        }
+    };
+
+    let mut file = String::from("$ cd /\n");
+    // Directories that are being listed, with children that are still left to visit.
+    let mut stack: Vec<std::vec::IntoIter<usize>> = Vec::new();
+    let mut current = Some(0);
+    loop {
+        if let Some(dir) = current.take() {
+            file.push_str("$ ls\n");
+            let mut listing: Vec<String> = children[dir]
+                .iter()
+                .map(|c| format!("dir {}", name(*c)))
+                .collect();
+            for _ in 0..rng.range(0..=size.width as u64) {
+                let extension = if rng.one_in(2) { ".txt" } else { "" };
+                listing.push(format!(
+                    "{} {}{extension}",
+                    rng.range(1..=300_000),
+                    name(rng.index(1000))
+                ));
+            }
+            rng.shuffle(&mut listing);
+            for line in listing {
+                file += &line;
+                file.push('\n');
+            }
+            stack.push(children[dir].clone().into_iter());
+        }
+
+        let Some(next) = stack.last_mut().and_then(|left| left.next()) else {
+            stack.pop();
+            if stack.is_empty() {
+                break;
+            }
+            file.push_str("$ cd ..\n");
+            continue;
+        };
+        file += &format!("$ cd {}\n", name(next));
+        current = Some(next);
+    }
+
+    file
+}
+
+// Solution of the day for the runner.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "No Space Left On Device";
+    type Input = Input;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(file: &str) -> Result<Input, ParseError> {
+        parse(file)
+    }
+
+    fn part_one(input: &Input) -> u64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> u64 {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> String {
+        generate(rng, size)
+    }
+}
@@ -0,0 +1,48 @@
+// Golden tests against the example input from the puzzle description.
+// Answers here are the ones given in the puzzle text, so they must never change.
+
+const EXAMPLE: &str = include_str!("../../test_input.txt");
+
+#[test]
+fn part_one_example() {
+    let input = day07::parse(EXAMPLE).unwrap();
+    assert_eq!(day07::part_one(&input), 95437);
+}
+
+#[test]
+fn part_two_example() {
+    let input = day07::parse(EXAMPLE).unwrap();
+    assert_eq!(day07::part_two(&input), 24933642);
+}
+
+// Sizes that add up past u64 are a parse error, not a panic.
+#[test]
+fn directory_size_overflow() {
+    let error = day07::parse("$ cd /\n$ ls\n18446744073709551615 a\n1 b").unwrap_err();
+    assert_eq!(error.expected, "directory sizes that fit into u64");
+
+    // Overflow in a nested directory is found too, even when each directory alone fits.
+    let file = "$ cd /\n$ ls\n18446744073709551615 a\ndir b\n$ cd b\n$ ls\n1 c";
+    let error = day07::parse(file).unwrap_err();
+    assert_eq!(error.expected, "directory sizes that fit into u64");
+}
+
+// Directory listed again starts empty, so files it had before don't count towards any size.
+#[test]
+fn listing_again_forgets_old_files() {
+    let file = "$ cd /\n$ ls\ndir a\n$ cd a\n$ ls\n18446744073709551615 x\n$ cd ..\n$ ls\ndir a\n$ cd a\n$ ls\n1 y";
+    let input = day07::parse(file).unwrap();
+    assert_eq!(input.size, 1);
+}
+
+// Blank line and CRLF line endings are reported where they are.
+#[test]
+fn whitespace_is_reported() {
+    let error = day07::parse("$ cd /\n\n$ ls\n1 a\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(error.found, "blank line");
+
+    let error = day07::parse("$ cd /\r\n$ ls\r\n1 a\r\n").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 7));
+    assert_eq!(error.found, "unexpected \\r");
+}
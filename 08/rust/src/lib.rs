@@ -0,0 +1,198 @@
+// Day 08: Treetop Tree House.
+use common::{Direction, Grid, ParseError, Rng, Size, Solution};
+
+// Puzzle day number.
+pub const DAY: u8 = 8;
+
+// Map of tree heights.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Forest {
+    // Grid that holds a map of trees.
+    pub trees: Grid<u32>,
+}
+
+impl Forest {
+    // Get a List of all trees in all directions from a tree. Order is up, down, left, right.
+    pub fn trees_around(&self, x: usize, y: usize) -> [Vec<u32>; 4] {
+        Direction::STRAIGHT.map(|d| self.trees.ray(x, y, d).copied().collect())
+    }
+}
+
+pub type Input = Forest;
+
+// Parse input into a map of trees.
+pub fn parse(file: &str) -> Result<Input, ParseError> {
+    // Treat each character as a tree height, each line is a row of trees.
+    let trees = Grid::parse(DAY, file, "tree height 0-9", |c| c.to_digit(10))?;
+
+    Ok(Forest { trees })
+}
+
+// Positions of every line of trees as seen from each side of the forest, nearest tree first.
+// Rows are looked at from the left and the right, columns from the top and the bottom.
+fn sight_lines(width: usize, height: usize) -> Vec<Vec<(usize, usize)>> {
+    let mut lines = Vec::new();
+    for y in 0..height {
+        let row: Vec<(usize, usize)> = (0..width).map(|x| (x, y)).collect();
+        lines.push(row.iter().rev().copied().collect());
+        lines.push(row);
+    }
+    for x in 0..width {
+        let column: Vec<(usize, usize)> = (0..height).map(|y| (x, y)).collect();
+        lines.push(column.iter().rev().copied().collect());
+        lines.push(column);
+    }
+    lines
+}
+
+// Amount of trees that are visible from outside of the grid.
+pub fn part_one(forest: &Input) -> usize {
+    let trees = &forest.trees;
+    let mut visible = Grid::new(trees.width(), trees.height(), false);
+
+    // Walking into the forest from a side, tree is visible from there if it's taller than every tree before it.
+    for line in sight_lines(trees.width(), trees.height()) {
+        let mut tallest: Option<u32> = None;
+        for position in line {
+            let tree = trees[position];
+            if tallest.is_none_or(|t| tree > t) {
+                visible[position] = true;
+                tallest = Some(tree);
+            }
+        }
+    }
+
+    visible.iter().filter(|(_, v)| **v).count()
+}
+
+// Highest scenic score possible for any tree.
+pub fn part_two(forest: &Input) -> usize {
+    let trees = &forest.trees;
+    let mut scenic_scores = Grid::new(trees.width(), trees.height(), 1usize);
+
+    // Walking a line, each tree looks back towards the side we came from.
+    for line in sight_lines(trees.width(), trees.height()) {
+        // Trees that can still block the view of a next tree: their index in line and height.
+        // Heights only go down from bottom to top, lower trees are hidden behind the next taller one.
+        let mut blocking: Vec<(usize, u32)> = Vec::new();
+        for (i, position) in line.into_iter().enumerate() {
+            let tree = trees[position];
+            while blocking.last().is_some_and(|(_, height)| *height < tree) {
+                blocking.pop();
+            }
+            // View ends at the first tree that is at least as tall, or at the edge.
+            let distance = blocking.last().map_or(i, |(j, _)| i - j);
+            scenic_scores[position] *= distance;
+            blocking.push((i, tree));
+        }
+    }
+
+    scenic_scores
+        .iter()
+        .map(|(_, score)| *score)
+        .max()
+        .unwrap_or(0)
+}
+
+// First solution, collecting trees in every direction from every tree into a new vector. Time grows with the
+// forest size times its width, but every tree is judged exactly as the puzzle text says.
+pub mod reference {
+    use super::Input;
+
+    // Function that will calculate trees scene score.
+    fn calculate_tree_scene_score(current_tree_height: u32, trees_on_its_way: &[u32]) -> usize {
+        let mut scenic_score: usize = 0;
+        // For each tree in the list
+        for tree in trees_on_its_way {
+            // Add a score point.
+            scenic_score += 1;
+            // If tree bloks view -> we have done calculating.
+            if *tree >= current_tree_height {
+                break;
+            }
+        }
+        scenic_score
+    }
+
+    // Amount of trees that are visible from outside of the grid.
+    pub fn part_one(forest: &Input) -> usize {
+        // Varibale to store total amout of visible trees.
+        let mut visible_trees: usize = 0;
+
+        // For each tree. Outer trees have nothing around them on one side, so they are visible by default.
+        for ((x, y), current_tree) in forest.trees.iter() {
+            // Check if all values in tree lists are lower than current tree height.
+            // If tree is visible from any direction -> increase counter.
+            if forest
+                .trees_around(x, y)
+                .iter()
+                .any(|trees| trees.iter().all(|v| v < current_tree))
+            {
+                visible_trees += 1;
+            }
+        }
+
+        visible_trees
+    }
+
+    // Highest scenic score possible for any tree.
+    pub fn part_two(forest: &Input) -> usize {
+        // List to store scenic scores of all trees.
+        let mut scenic_scores: Vec<usize> = Vec::new();
+
+        // For each tree. Outer trees see nothing in one of directions, so their score is always zero.
+        for ((x, y), current_tree) in forest.trees.iter() {
+            // Calculate tree scene score for every direction and multiply those.
+            let scenic_score: usize = forest
+                .trees_around(x, y)
+                .iter()
+                .map(|trees| calculate_tree_scene_score(*current_tree, trees))
+                .product();
+
+            // Push scenic score to a list of scores.
+            scenic_scores.push(scenic_score);
+        }
+
+        scenic_scores.into_iter().max().unwrap_or(0)
+    }
+}
+
+// Random forest of `size.width` by `size.height` trees.
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    (0..size.height.max(1))
+        .map(|_| {
+            let mut row: String = (0..size.width.max(1))
+                .map(|_| char::from(b'0' + rng.below(10) as u8))
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+// Solution of the day for the runner.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Treetop Tree House";
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(file: &str) -> Result<Input, ParseError> {
+        parse(file)
+    }
+
+    fn part_one(input: &Input) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> usize {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> String {
+        generate(rng, size)
+    }
+}
@@ -0,0 +1,16 @@
+// Golden tests against the example input from the puzzle description.
+// Answers here are the ones given in the puzzle text, so they must never change.
+
+const EXAMPLE: &str = include_str!("../../test_input.txt");
+
+#[test]
+fn part_one_example() {
+    let input = day08::parse(EXAMPLE).unwrap();
+    assert_eq!(day08::part_one(&input), 21);
+}
+
+#[test]
+fn part_two_example() {
+    let input = day08::parse(EXAMPLE).unwrap();
+    assert_eq!(day08::part_two(&input), 8);
+}
@@ -0,0 +1,32 @@
+// Day 08 part_one follows sight lines from every edge and part_two keeps a stack of trees that can still block
+// the view, `reference` walks from every tree to the edge in all four directions, like the puzzle describes it.
+use proptest::prelude::*;
+
+// Random forests from 1x1 to 15x15 trees, single rows and columns have every tree on the edge. Heights are
+// either any digit, or only 0 to 2 so trees of equal height block each other's view all the time.
+fn input() -> impl Strategy<Value = String> {
+    (1usize..16, 1usize..16, prop_oneof![Just(10u32), Just(3u32)]).prop_flat_map(
+        |(width, height, heights)| {
+            prop::collection::vec(0..heights, width * height).prop_map(move |trees| {
+                trees
+                    .chunks(width)
+                    .map(|row| row.iter().map(|t| t.to_string()).collect::<String>() + "\n")
+                    .collect()
+            })
+        },
+    )
+}
+
+proptest! {
+    #[test]
+    fn part_one_matches_reference(file in input()) {
+        let input = day08::parse(&file).unwrap();
+        prop_assert_eq!(day08::part_one(&input), day08::reference::part_one(&input));
+    }
+
+    #[test]
+    fn part_two_matches_reference(file in input()) {
+        let input = day08::parse(&file).unwrap();
+        prop_assert_eq!(day08::part_two(&input), day08::reference::part_two(&input));
+    }
+}
@@ -0,0 +1,216 @@
+// Day 09: Rope Bridge.
+use common::error::finish;
+use common::{Direction, ParseError, Point2, Rng, Size, Solution};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, newline};
+use nom::combinator::value;
+use nom::multi::separated_list1;
+use nom::sequence::separated_pair;
+use nom::IResult;
+use std::collections::HashSet;
+
+// Puzzle day number.
+pub const DAY: u8 = 9;
+
+// Struct to hold each move.
+#[derive(Debug, Clone, Copy)]
+pub struct Move {
+    pub dir: Direction,
+    pub amount: u32,
+}
+
+impl Move {
+    pub fn new(dir: Direction, amount: u32) -> Self {
+        Self { dir, amount }
+    }
+
+    // Create a move from reference point of 0,0.
+    // Diagonal moves only go straight at 45 degrees, so amount is the Chebyshev distance.
+    pub fn from_point(point: Point2) -> Self {
+        Self {
+            dir: Direction::towards(point),
+            amount: point.chebyshev(Point2::ORIGIN) as u32,
+        }
+    }
+}
+
+// Head of rope.
+#[derive(Debug, Clone, Copy, Default)]
+struct Head {
+    pos: Point2,
+}
+
+impl Head {
+    // Move functione of head of the rope.
+    fn mov(&mut self, dir: Direction) {
+        self.pos += dir.step();
+    }
+}
+
+// Tail of the rope.
+#[derive(Debug, Clone, Copy, Default)]
+struct Tail {
+    pos: Point2,
+}
+
+impl Tail {
+    // Move function of tail.
+    fn mov(&mut self, mov: Move) {
+        self.pos += mov.dir.step() * mov.amount as i64;
+    }
+
+    // Function that calculates how tail should move to head.
+    fn move_to_head(&mut self, head: Head) {
+        // Tail stays in place while it touches the head, diagonally too.
+        if self.pos.chebyshev(head.pos) > 1 {
+            let mut mov = Move::from_point(head.pos - self.pos);
+            // Stop one step before the head.
+            mov.amount -= 1;
+            self.mov(mov);
+        }
+    }
+}
+
+// Struct to hold rope itself.
+#[derive(Debug, Clone)]
+pub struct Rope {
+    head: Head,
+    tail: Vec<Tail>,
+}
+
+impl Rope {
+    // Create a rope with required amount of tails.
+    pub fn new(tails: usize) -> Self {
+        Self {
+            head: Head::default(),
+            tail: vec![Tail::default(); tails],
+        }
+    }
+
+    // Move whole rope.
+    pub fn mov(&mut self, command: Move) {
+        // Move head first.
+        self.head.mov(command.dir);
+        // Then move each of the tails.
+        for i in 0..self.tail.len() {
+            // Use this to get reference to the previous tail.
+            let copy_tail = self.tail.clone();
+            // Get current tail.
+            let current_tail = self.tail.get_mut(i).unwrap();
+            // It it's a first one.
+            if i == 0 {
+                // Move to head.
+                current_tail.move_to_head(self.head);
+            } else {
+                // Move to previous tail.
+                let target_tail = copy_tail.get(i - 1).unwrap();
+                // Mimic tail as head, because of strict types.
+                current_tail.move_to_head(Head {
+                    pos: target_tail.pos,
+                });
+            }
+        }
+    }
+}
+
+// Nom function to parse move instuctions.
+fn move_parser(i: &str) -> IResult<&str, Move> {
+    let direction = alt((
+        value(Direction::Right, char('R')),
+        value(Direction::Left, char('L')),
+        value(Direction::Up, char('U')),
+        value(Direction::Down, char('D')),
+    ));
+    let (i, (direction, steps)) =
+        separated_pair(direction, tag(" "), nom::character::complete::u32)(i)?;
+
+    Ok((i, Move::new(direction, steps)))
+}
+
+// Nom function to parse whole file.
+fn file_parser(i: &str) -> IResult<&str, Vec<Move>> {
+    separated_list1(newline, move_parser)(i)
+}
+
+// Moves that head of the rope does.
+pub type Input = Vec<Move>;
+
+// Parse input into List of moves.
+pub fn parse(file: &str) -> Result<Input, ParseError> {
+    finish(DAY, file, file_parser(file), "move like \"R 4\"")
+}
+
+// Amount of unique positions that last tail of the rope with required amount of tails visits.
+pub fn count_tail_positions(moves: &Input, tails: usize) -> usize {
+    // Create rope that will follow move instuctions.
+    let mut rope = Rope::new(tails);
+    // We will use HashSet to store unique Points which tail has visited, starting with where it is now.
+    // Only positions are kept, so memory grows with the amount of places visited and not of steps made.
+    let mut positions_history: HashSet<Point2> = HashSet::new();
+    if let Some(tail) = rope.tail.last() {
+        positions_history.insert(tail.pos);
+    }
+
+    // For each move in parsed moves.
+    for mov in moves {
+        // Move X amount of times.
+        for _ in 0..mov.amount {
+            rope.mov(*mov);
+            // Insert last tail position to a position history. Only unique values will be stored.
+            if let Some(tail) = rope.tail.last() {
+                positions_history.insert(tail.pos);
+            }
+        }
+    }
+
+    // Cound amount of entries stored in the HashSet. This is our puzzle result.
+    positions_history.len()
+}
+
+// Positions visited by tail of the rope with one tail.
+pub fn part_one(moves: &Input) -> usize {
+    count_tail_positions(moves, 1)
+}
+
+// Positions visited by last tail of the rope with nine tails.
+pub fn part_two(moves: &Input) -> usize {
+    count_tail_positions(moves, 9)
+}
+
+// Random input with `size.amount` motions, each from 1 to `size.width` steps long.
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    (0..size.amount.max(1))
+        .map(|_| {
+            let direction = rng.choose(&['U', 'D', 'L', 'R']);
+            format!("{direction} {}\n", rng.range(1..=size.width.max(1) as u64))
+        })
+        .collect()
+}
+
+// Solution of the day for the runner.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Rope Bridge";
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(file: &str) -> Result<Input, ParseError> {
+        parse(file)
+    }
+
+    fn part_one(input: &Input) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> usize {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> String {
+        generate(rng, size)
+    }
+}
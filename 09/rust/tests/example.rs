@@ -0,0 +1,33 @@
+// Golden tests against the example input from the puzzle description.
+// Answers here are the ones given in the puzzle text, so they must never change.
+
+const EXAMPLE: &str = include_str!("../../test_input.txt");
+
+#[test]
+fn part_one_example() {
+    let input = day09::parse(EXAMPLE).unwrap();
+    assert_eq!(day09::part_one(&input), 13);
+}
+
+#[test]
+fn part_two_example() {
+    let input = day09::parse(EXAMPLE).unwrap();
+    assert_eq!(day09::part_two(&input), 1);
+}
+
+// Larger example from the second part, where tail really moves.
+const LARGE_EXAMPLE: &str = include_str!("../../test_input_large.txt");
+
+#[test]
+fn part_two_large_example() {
+    let input = day09::parse(LARGE_EXAMPLE).unwrap();
+    assert_eq!(day09::part_two(&input), 36);
+}
+
+// Blank line between moves is pointed at, not the good move after it.
+#[test]
+fn blank_line_is_reported() {
+    let error = day09::parse("R 4\n\nR 5\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    assert_eq!(error.found, "blank line");
+}
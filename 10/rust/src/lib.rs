@@ -0,0 +1,334 @@
+// Day 10: Cathode-Ray Tube.
+use common::error::finish;
+use common::{Bitmap, ParseError, Rng, Size, Solution};
+use nom::character::complete::newline;
+use nom::{branch::alt, multi::separated_list1};
+use nom::{bytes::complete::tag, sequence::preceded, IResult};
+
+// Puzzle day number.
+pub const DAY: u8 = 10;
+
+// How long each instruction takes.
+pub const NOOP_CYCLES: u32 = 1;
+pub const ADDX_CYCLES: u32 = 2;
+
+// CRT screen size.
+pub const CRT_WIDTH: u32 = 40;
+pub const CRT_HEIGHT: u32 = 6;
+
+// Instruction that will be executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Addx(i32),
+    Noop,
+}
+
+// CPU that will execute Opcodes.
+#[derive(Debug)]
+#[allow(clippy::upper_case_acronyms)]
+struct CPU {
+    // Register X that we will modify. Wider than addx values, so adding them up can't overflow.
+    x: i64,
+    // Amount of ticks that has passed.
+    ticks: u32,
+    // Current Instruction that is executed.
+    current_op: Op,
+    // Amount of ticks that current instruction taks.
+    current_ticks: u32,
+    // List of all instructions that will be executed.
+    program: Vec<Op>,
+    // Is CPU ready to fetch next instruction.
+    ready_to_fetch: bool,
+}
+
+// CPU initialization.
+impl Default for CPU {
+    fn default() -> Self {
+        Self {
+            x: 1,
+            ticks: 1,
+            current_op: Op::Noop,
+            current_ticks: 0,
+            program: Vec::new(),
+            ready_to_fetch: true,
+        }
+    }
+}
+
+// CPU methods.
+impl CPU {
+    // Execute whole program and return pairs of CPU cycle and X register at interesting cycles.
+    fn execute(&mut self) -> Vec<(u32, i64)> {
+        // List to store execution results.
+        let mut res: Vec<(u32, i64)> = Vec::new();
+
+        // CPU loop. If we run out of instructions -> program is completed.
+        while self.execute_one_cycle().is_some() {
+            // Increment total ticks executed.
+            self.ticks += 1;
+
+            // Instead of having a lot of if. I have decided to check if current tick is an interesting value.
+            if [20, 60, 100, 140, 180, 220].contains(&self.ticks) {
+                // Safe current tick and register value.
+                res.push((self.ticks, self.x));
+            }
+        }
+
+        res
+    }
+
+    // Execute one CPU cycle.
+    fn execute_one_cycle(&mut self) -> Option<()> {
+        // If CPU is ready to fetch new instruction.
+        if self.ready_to_fetch {
+            match self.fetch_opcode() {
+                // Set new instruction as current.
+                Some(o) => self.current_op = o,
+                // Else -> we run out of instructions to do. Program is completed.
+                None => return None,
+            }
+        }
+
+        // One CPU cycle.
+        self.cycle();
+
+        // Return success.
+        Some(())
+    }
+
+    // Actually do one cycle.
+    fn cycle(&mut self) {
+        // Increment current ticks that is currently executed.
+        self.current_ticks += 1;
+        // Match current operation with current ticks that are executed.
+        // If required amount of current ticks has passed -> executed instruction & reset current ticks.
+        // Else block cpu fetching.
+        match (self.current_op, self.current_ticks) {
+            (Op::Noop, u32::MIN..=u32::MAX) => self.execute_noop(),
+            (Op::Addx(_), 0..=1) => self.ready_to_fetch = false,
+            (Op::Addx(v), ADDX_CYCLES..=u32::MAX) => self.execute_addx(v),
+        }
+    }
+
+    // Execute Noop instructon.
+    fn execute_noop(&mut self) {
+        // Reset current ticks executed.
+        self.current_ticks = 0;
+        // Unblock CPU fetching.
+        self.ready_to_fetch = true;
+    }
+
+    // Execute Addx instructon.
+    fn execute_addx(&mut self, value: i32) {
+        // Modify X register.
+        self.x += i64::from(value);
+        // Prepare CPU for next instruction.
+        self.execute_noop();
+    }
+
+    // Fetch next opcode.
+    fn fetch_opcode(&mut self) -> Option<Op> {
+        self.program.pop()
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+struct CRT {
+    x: u32,
+    // Pixels that were drawn so far.
+    screen: Bitmap,
+}
+
+// CRT initialization.
+impl Default for CRT {
+    fn default() -> Self {
+        Self {
+            x: 0,
+            screen: Bitmap::new(CRT_WIDTH as usize, CRT_HEIGHT as usize),
+        }
+    }
+}
+
+impl CRT {
+    // Execute one CRT cycle.
+    fn execute_one_cycle(&mut self, reg_x: i64, ticks: u32) {
+        // Actually do one cycle.
+        self.cycle(reg_x, ticks);
+        // Increment x counter.
+        self.x += 1;
+        // Used to have more logic there, that's why this method exists...
+    }
+
+    // Actually do one cycle.
+    fn cycle(&mut self, reg_x: i64, ticks: u32) {
+        // Every 40 ticks we move to the next row.
+        let y = (ticks - 1) / CRT_WIDTH;
+
+        // If we are in the end of the row -> reset x counter.
+        if self.x >= CRT_WIDTH {
+            self.x = 0;
+        }
+
+        // If CPU register X has value x +- 1 relative to current crt x counter -> it is a sprite.
+        // Else it's nothing. Compare as signed, sprite might be partially off the screen.
+        let lit = (reg_x - i64::from(self.x)).abs() <= 1;
+        self.screen.set(self.x as usize, y as usize, lit);
+    }
+}
+
+// Struct that holds Personal Computer which consists out of CPU & CRT.
+#[allow(clippy::upper_case_acronyms)]
+struct PC {
+    cpu: CPU,
+    crt: CRT,
+    ticks: u32,
+}
+
+impl PC {
+    // PC constructor.
+    fn new(cpu: CPU, crt: CRT, ticks: u32) -> Self {
+        Self { cpu, crt, ticks }
+    }
+
+    // Execute CPU program & render CRT.
+    fn execute(&mut self) {
+        loop {
+            // If CPU is ready to execute next instruction.
+            if self.cpu.ready_to_fetch {
+                // Execute it.
+                let res = self.cpu.execute_one_cycle();
+                // If there are no instructions left -> return.
+                if res.is_none() {
+                    break;
+                }
+                // Render CRT.
+                self.crt.execute_one_cycle(self.cpu.x, self.ticks);
+            } else {
+                // CPU is busy -> render CRC first.
+                self.crt.execute_one_cycle(self.cpu.x, self.ticks);
+                // Then execute CPU cycle.
+                let res = self.cpu.execute_one_cycle();
+                // If there are no instructions left -> return.
+                if res.is_none() {
+                    break;
+                }
+            }
+            // Increment cycle counter.
+            self.ticks += 1;
+        }
+    }
+}
+
+// Nom addx parser.
+fn parse_addx(i: &str) -> IResult<&str, Op> {
+    let (i, res) = preceded(tag("addx "), nom::character::complete::i32)(i)?;
+
+    Ok((i, Op::Addx(res)))
+}
+
+// Nom noop parser.
+fn parse_noop(i: &str) -> IResult<&str, Op> {
+    let (i, _) = tag("noop")(i)?;
+
+    Ok((i, Op::Noop))
+}
+
+// Nom opcode parser.
+fn parse_opcode(i: &str) -> IResult<&str, Op> {
+    alt((parse_addx, parse_noop))(i)
+}
+
+// Nom file parser.
+fn parse_file(i: &str) -> IResult<&str, Vec<Op>> {
+    separated_list1(newline, parse_opcode)(i)
+}
+
+// Program that CPU will execute, in order.
+pub type Input = Vec<Op>;
+
+// Parse input into List of instructions.
+pub fn parse(file: &str) -> Result<Input, ParseError> {
+    finish(
+        DAY,
+        file,
+        parse_file(file),
+        "instruction like \"addx 3\" or \"noop\"",
+    )
+}
+
+// Create a CPU that will execute the program.
+fn new_cpu(program: &Input) -> CPU {
+    // Reverse the order as we are interested in first instruction.
+    let mut program = program.clone();
+    program.reverse();
+
+    // Initiate CPU with default values and the program.
+    CPU {
+        program,
+        ..Default::default()
+    }
+}
+
+// Sum of signal strengths during interesting cycles.
+pub fn part_one(program: &Input) -> i64 {
+    // Execute CPU program and store pairs of CPU cycle and X register.
+    let result = new_cpu(program).execute();
+
+    // Multiply cycles by X register value and sum those up.
+    result
+        .into_iter()
+        .map(|(ticks, x)| i64::from(ticks) * x)
+        .sum()
+}
+
+// Image rendered on CRT screen.
+pub fn part_two(program: &Input) -> Bitmap {
+    // Iinitialize PC with CPU & CRT.
+    let mut pc = PC::new(new_cpu(program), CRT::default(), 1);
+    // Execute CPU program.
+    pc.execute();
+
+    pc.crt.screen
+}
+
+// Random program with `size.amount` instructions, about a third of them are "noop".
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    (0..size.amount.max(1))
+        .map(|_| {
+            if rng.one_in(3) {
+                "noop\n".to_string()
+            } else {
+                let value = rng.range(1..=10) as i64;
+                let value = if rng.one_in(2) { -value } else { value };
+                format!("addx {value}\n")
+            }
+        })
+        .collect()
+}
+
+// Solution of the day for the runner.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Cathode-Ray Tube";
+    type Input = Input;
+    type Answer1 = i64;
+    type Answer2 = Bitmap;
+
+    fn parse(file: &str) -> Result<Input, ParseError> {
+        parse(file)
+    }
+
+    fn part_one(input: &Input) -> i64 {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> Bitmap {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> String {
+        generate(rng, size)
+    }
+}
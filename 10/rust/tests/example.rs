@@ -0,0 +1,58 @@
+// Golden tests against the example input from the puzzle description.
+// Answers here are the ones given in the puzzle text, so they must never change.
+
+const EXAMPLE: &str = include_str!("../../test_input.txt");
+
+#[test]
+fn part_one_example() {
+    let input = day10::parse(EXAMPLE).unwrap();
+    assert_eq!(day10::part_one(&input), 13140);
+}
+
+// Picture that example program draws on CRT.
+const EXAMPLE_SCREEN: [&str; 6] = [
+    "##..##..##..##..##..##..##..##..##..##..",
+    "###...###...###...###...###...###...###.",
+    "####....####....####....####....####....",
+    "#####.....#####.....#####.....#####.....",
+    "######......######......######......####",
+    "#######.......#######.......#######.....",
+];
+
+#[test]
+fn part_two_example() {
+    let input = day10::parse(EXAMPLE).unwrap();
+    assert_eq!(day10::part_two(&input).rows(), EXAMPLE_SCREEN);
+}
+
+// X register goes past i32 when big addx values are added up, this used to overflow.
+#[test]
+fn register_past_i32() {
+    let input = day10::parse("addx 2147483647\naddx 1").unwrap();
+    assert_eq!(day10::part_one(&input), 0);
+    assert!(day10::part_two(&input).rows()[0].starts_with("##.."));
+}
+
+// Sprite far to the left of the screen, distance to it used to overflow.
+#[test]
+fn sprite_far_off_screen() {
+    let input = day10::parse("addx -2147483647\naddx -2\nnoop").unwrap();
+    let screen = day10::part_two(&input);
+    assert!(screen.rows()[0].starts_with("##..."));
+}
+
+// Signal strength of a huge register value, 20 * X used to overflow.
+#[test]
+fn signal_strength_past_i32() {
+    let file = format!("addx 2147483646\n{}", vec!["noop"; 20].join("\n"));
+    let input = day10::parse(&file).unwrap();
+    assert_eq!(day10::part_one(&input), 20 * 2147483647);
+}
+
+// CRLF line endings are reported at the \r, not at the next instruction which is fine.
+#[test]
+fn crlf_is_reported() {
+    let error = day10::parse("addx 3\r\nnoop\r\n").unwrap_err();
+    assert_eq!((error.line, error.column), (1, 7));
+    assert_eq!(error.found, "unexpected \\r");
+}
@@ -0,0 +1,13 @@
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = common::input::from_args(day11::DAY)?;
+    let input = day11::parse(&input)?;
+
+    match day11::part_two(&input) {
+        Some(business) => println!("Monkey business: {business}"),
+        None => println!("Monkey business got too big for usize."),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,567 @@
+// Day 11: Monkey in the Middle.
+use common::error::finish;
+use common::records::{records, strip_bom};
+use common::{ParseError, Rng, Size, Solution};
+use nom::branch::alt;
+use nom::character::complete::{line_ending, space0};
+use nom::combinator::{map, value};
+use nom::sequence::terminated;
+use nom::{bytes::complete::tag, multi::separated_list1};
+
+use nom::{
+    sequence::{delimited, preceded},
+    IResult,
+};
+
+// Puzzle day number.
+pub const DAY: u8 = 11;
+
+// Type that will match any operation over 2 worry levels. Worry levels are u64, but operations are done
+// in u128, where even multiplying two of the biggest u64's can't overflow.
+pub type Binop = fn(u128, u128) -> u128;
+
+// Add function. Pointer to which will be stored inside Operation struct.
+fn add(lh: u128, rh: u128) -> u128 {
+    lh + rh
+}
+
+// Multiply function. Pointer to which will be stored inside Operation struct.
+fn mul(lh: u128, rh: u128) -> u128 {
+    lh * rh
+}
+
+// Enum that might hold 2 variants. A number or and old value. These define rh operand of Binop type function.
+#[derive(Debug, Copy, Clone)]
+pub enum Operand {
+    Old,
+    Number(u64),
+}
+
+// Struct that will hold Operation that should be done on the number itself and it's rh operand.
+#[derive(Debug, Copy, Clone)]
+pub struct Operation {
+    operation: Binop,
+    operand: Operand,
+}
+
+impl Operation {
+    pub fn new(operation: Binop, operand: Operand) -> Self {
+        Self { operation, operand }
+    }
+
+    // Execute operation itself. Result is u128, it's relief that brings it back to u64.
+    pub fn execute(&self, old_value: u64) -> u128 {
+        let old_value = u128::from(old_value);
+        match self.operand {
+            // If Operand enum is Old. Than we apply operation with lh and rh operands set to old_value.
+            Operand::Old => (self.operation)(old_value, old_value),
+            // If Operand enum is a Number. We apply operation with lh set to old_value and rh set to number stored inside of this enum.
+            Operand::Number(n) => (self.operation)(old_value, u128::from(n)),
+        }
+    }
+}
+
+// Struct that will hold all of the required information about monkey.
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    // It's id.
+    id: usize,
+    // List of items worry levels it holds.
+    items: Vec<u64>,
+    // Operation struct that will be used to do manipulation with worry levels.
+    operation: Operation,
+    // Number that will be used for % branching checks.
+    divisible: u64,
+    // Id of monkey we pass new worry value if brancking check passes.
+    id_true: usize,
+    // Id of monkey we pass new worry value if brancking check fails.
+    id_false: usize,
+    // Amount of items monkey has inspected.
+    inspected: usize,
+}
+
+impl Monkey {
+    fn new(
+        id: usize,
+        items: Vec<u64>,
+        operation: Operation,
+        divisible: u64,
+        id_true: usize,
+        id_false: usize,
+        inspected: usize,
+    ) -> Self {
+        Self {
+            id,
+            items,
+            operation,
+            divisible,
+            id_true,
+            id_false,
+            inspected,
+        }
+    }
+}
+
+// How worry level gets lower after monkey inspects an item and gets bored with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relief {
+    // Worry level is divided by this number, rounding down.
+    DivideBy(u64),
+    // Worry level stays as it is. It's kept small by taking it modulo least common multiple of every monkey
+    // divisible field, which doesn't change result of any monkey divisible test. Worry levels are below it,
+    // so `old * old` can be past u64 before the modulo, that's why operations are done in u128.
+    None,
+}
+
+// Struct that holds game itself.
+#[derive(Debug, Clone)]
+pub struct KeepAway {
+    // List of all Monkeys that will play the game.
+    monkeys: Vec<Monkey>,
+    // How worry levels are reduced after each inspection.
+    relief: Relief,
+    // Least common multiple of all divisible fields, worry levels are kept below it when there is no relief.
+    // Product would do too, but it gets too big when many monkeys share a divisor.
+    modulus: u64,
+}
+
+// Greatest common divisor.
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl KeepAway {
+    pub fn new(monkeys: Vec<Monkey>, relief: Relief) -> Self {
+        let modulus = monkeys
+            .iter()
+            .map(|m| m.divisible)
+            .fold(1, |lcm, d| lcm / gcd(lcm, d) * d);
+        Self {
+            monkeys,
+            relief,
+            modulus,
+        }
+    }
+
+    // Worry level after monkey gets bored with an item, None if it's still too big for u64 or relief divides by 0.
+    fn relieve(&self, worry: u128) -> Option<u64> {
+        match self.relief {
+            Relief::DivideBy(n) => u64::try_from(worry.checked_div(u128::from(n))?).ok(),
+            // Remainder is below modulus, which is u64.
+            Relief::None => Some((worry % u128::from(self.modulus)) as u64),
+        }
+    }
+
+    // Execute one game round. Fails with index of the monkey that made a worry level too big for u64,
+    // game can't go on after that.
+    pub fn round(&mut self) -> Result<(), usize> {
+        // For each monkey in the Monkey list.
+        for i in 0..self.monkeys.len() {
+            // Take all items out of monkey inventory. All of them will be thrown to other monkeys.
+            let items = std::mem::take(&mut self.monkeys[i].items);
+            // All items we are going to work with are inspected by this monkey, so we increase monkey inspected counter.
+            self.monkeys[i].inspected += items.len();
+
+            // For each item inside of monkey item inventory.
+            for item in items {
+                let monkey = &self.monkeys[i];
+
+                // Calculate new worry value by doing operation specified in the monkey struct.
+                // Then reduce worry level by required amount.
+                let Some(new_value) = self.relieve(monkey.operation.execute(item)) else {
+                    return Err(i);
+                };
+
+                // Id of the monkey we will throw this item to. Do the div test to find it.
+                let id_throw = if new_value.is_multiple_of(monkey.divisible) {
+                    // If passed -> id of throw to monkey is current monkey id_true field.
+                    monkey.id_true
+                } else {
+                    // Else it's id_false field.
+                    monkey.id_false
+                };
+
+                // Add this new item to the inventory of id_throw monkey.
+                self.monkeys[id_throw].items.push(new_value);
+            }
+        }
+
+        Ok(())
+    }
+
+    // Method that is used to calculate final puzzle score. None if it doesn't fit into usize.
+    pub fn monkey_business(&self) -> Option<usize> {
+        // Collect every monkey inspected field into a List.
+        let mut inspections: Vec<usize> = self.monkeys.iter().map(|m| m.inspected).collect();
+        // Sort this list.
+        inspections.sort_unstable();
+        // Reverse it, so top 2 values are in the top of the list.
+        inspections.reverse();
+
+        // Caclulate final score.
+        inspections[0].checked_mul(inspections[1])
+    }
+
+    // Id of every monkey with amount of items it has inspected so far, for looking into a game.
+    pub fn inspections(&self) -> Vec<(usize, usize)> {
+        self.monkeys.iter().map(|m| (m.id, m.inspected)).collect()
+    }
+}
+
+// Nom stuff bellow. Check other solutions to get a better perspective on what nom parsers do.
+
+// Nom parser for the end of a line: "\n" or "\r\n", spaces before it are fine too.
+fn end_of_line(i: &str) -> IResult<&str, &str> {
+    preceded(space0, line_ending)(i)
+}
+
+// Nom parser to parse monkey id.
+fn parse_monkey_id(i: &str) -> IResult<&str, usize> {
+    let (i, id) = terminated(
+        delimited(
+            tag("Monkey "),
+            nom::character::complete::u32,
+            nom::character::complete::char(':'),
+        ),
+        end_of_line,
+    )(i)?;
+
+    Ok((i, id as usize))
+}
+
+// Nom parser to parse monkey inventory.
+fn parse_monkey_items(i: &str) -> IResult<&str, Vec<u64>> {
+    let (i, items) = delimited(
+        tag("  Starting items: "),
+        separated_list1(tag(", "), nom::character::complete::u64),
+        end_of_line,
+    )(i)?;
+
+    Ok((i, items))
+}
+
+// Nom parser to parse monkey operations.
+fn parse_monkey_operation(i: &str) -> IResult<&str, Operation> {
+    let (i, op_char) = terminated(
+        alt((
+            nom::character::complete::char('*'),
+            nom::character::complete::char('+'),
+        )),
+        nom::character::complete::char(' '),
+    )(i)?;
+
+    let (i, rhs) = alt((
+        value(Operand::Old, tag("old")),
+        map(nom::character::complete::u64, Operand::Number),
+    ))(i)?;
+
+    // Operator char was already checked by the parser, so it's one of those two.
+    let binop: Binop = if op_char == '+' { add } else { mul };
+
+    Ok((i, Operation::new(binop, rhs)))
+}
+
+// Nom parser to parse monkey operations line.
+fn parse_monkey_operation_line(i: &str) -> IResult<&str, Operation> {
+    let (i, operation) = delimited(
+        tag("  Operation: new = old "),
+        parse_monkey_operation,
+        end_of_line,
+    )(i)?;
+
+    Ok((i, operation))
+}
+
+// Nom parser to parse monkey divisible field.
+fn parse_monkey_divisible(i: &str) -> IResult<&str, u64> {
+    let (i, divisible) = delimited(
+        tag("  Test: divisible by "),
+        nom::character::complete::u64,
+        end_of_line,
+    )(i)?;
+
+    Ok((i, divisible))
+}
+
+// Nom parser to parse monkey if true field.
+fn parse_monkey_true(i: &str) -> IResult<&str, usize> {
+    let (i, id) = delimited(
+        tag("    If true: throw to monkey "),
+        nom::character::complete::u32,
+        end_of_line,
+    )(i)?;
+
+    Ok((i, id as usize))
+}
+
+// Nom parser to parse monkey if false field.
+fn parse_monkey_false(i: &str) -> IResult<&str, usize> {
+    let (i, id) = preceded(
+        tag("    If false: throw to monkey "),
+        nom::character::complete::u32,
+    )(i)?;
+
+    Ok((i, id as usize))
+}
+
+// Nom parser to parse whole monkey struct.
+fn parse_monkey(i: &str) -> IResult<&str, Monkey> {
+    let (i, id) = parse_monkey_id(i)?;
+    let (i, items) = parse_monkey_items(i)?;
+    let (i, operation) = parse_monkey_operation_line(i)?;
+    let (i, divisible) = parse_monkey_divisible(i)?;
+    let (i, id_true) = parse_monkey_true(i)?;
+    let (i, id_false) = parse_monkey_false(i)?;
+
+    Ok((
+        i,
+        Monkey::new(id, items, operation, divisible, id_true, id_false, 0),
+    ))
+}
+
+// Monkeys as they are at the start of the game.
+pub type Input = Vec<Monkey>;
+
+// Text after `prefix` on every line that starts with it, with index of that line and the line itself.
+// Each monkey has exactly one such line, so they come in the order of monkeys.
+fn numbers_after<'a>(file: &'a str, prefix: &str) -> Vec<(usize, &'a str, &'a str)> {
+    file.lines()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, line, line.strip_prefix(prefix)?.trim_end())))
+        .collect()
+}
+
+// Parse input into List of all monkeys.
+pub fn parse(file: &str) -> Result<Input, ParseError> {
+    // Each monkey is a record of lines, separated from others by blank lines.
+    // Records are parts of the file, so nom errors point into it.
+    let file = strip_bom(file);
+    let monkeys = records(file)
+        .map(|monkey| finish(DAY, file, parse_monkey(monkey.text), "monkey description"))
+        .collect::<Result<Vec<Monkey>, ParseError>>()?;
+
+    // Monkey business multiplies inspections of two most active monkeys.
+    if monkeys.len() < 2 {
+        return Err(ParseError::at_end(DAY, file, "at least two monkeys"));
+    }
+
+    // Monkeys throw items by their numbers, which are only looked up by position. Those must be the same.
+    let ids = numbers_after(file, "Monkey ");
+    for (index, (monkey, (i, line, number))) in monkeys.iter().zip(ids).enumerate() {
+        if monkey.id != index {
+            let number = number.trim_end_matches(':');
+            let expected = format!("monkey number {index}");
+            return Err(ParseError::in_line(DAY, i, line, number, expected));
+        }
+    }
+
+    // Divisible numbers are used for % checks, and part two keeps worry levels below their least common multiple.
+    let mut lcm: u64 = 1;
+    let divisibles = numbers_after(file, "  Test: divisible by ");
+    for (monkey, (i, line, number)) in monkeys.iter().zip(divisibles) {
+        let d = monkey.divisible;
+        lcm = match (lcm / gcd(lcm, d.max(1))).checked_mul(d) {
+            Some(lcm) if lcm > 0 => lcm,
+            Some(_) => {
+                return Err(ParseError::in_line(
+                    DAY,
+                    i,
+                    line,
+                    number,
+                    "number above zero",
+                ))
+            }
+            None => {
+                let expected = "number that keeps least common multiple of all of them in 64 bits";
+                return Err(ParseError::in_line(DAY, i, line, number, expected));
+            }
+        };
+    }
+
+    // Items can be thrown only to monkeys that exist.
+    let expected = format!("monkey number from 0 to {}", monkeys.len() - 1);
+    let targets = numbers_after(file, "    If true: throw to monkey ")
+        .into_iter()
+        .zip(numbers_after(file, "    If false: throw to monkey "));
+    for (monkey, (if_true, if_false)) in monkeys.iter().zip(targets) {
+        for (id, (i, line, number)) in [(monkey.id_true, if_true), (monkey.id_false, if_false)] {
+            if id >= monkeys.len() {
+                return Err(ParseError::in_line(DAY, i, line, number, expected.as_str()));
+            }
+        }
+    }
+
+    Ok(monkeys)
+}
+
+// Monkey business after playing required amount of rounds with given relief.
+// None if a worry level gets too big for u64, which can only happen when relief divides them,
+// if monkey business gets too big for usize, or if relief divides by 0.
+pub fn play(monkeys: &Input, rounds: usize, relief: Relief) -> Option<usize> {
+    if relief == Relief::DivideBy(0) {
+        return None;
+    }
+
+    // Initiate new game with List of parsed monkeys.
+    let mut game = KeepAway::new(monkeys.clone(), relief);
+
+    // Do all the rounds.
+    for _ in 0..rounds {
+        game.round().ok()?;
+    }
+
+    // Calculate final score.
+    game.monkey_business()
+}
+
+// Monkey business after 20 rounds, worry level is divided by 3 after each inspection.
+// Dividing by 3 doesn't keep worry levels small when monkeys square them, None if one gets too big for u64.
+pub fn part_one(monkeys: &Input) -> Option<usize> {
+    play(monkeys, 20, Relief::DivideBy(3))
+}
+
+// Monkey business after 10000 rounds without any relief.
+// Worry levels are kept below modulus, but monkey business itself can get too big, None then.
+pub fn part_two(monkeys: &Input) -> Option<usize> {
+    play(monkeys, 10_000, Relief::None)
+}
+
+// Monkey as the generator sees it, easier to simulate and print than Monkey.
+struct MonkeyPlan {
+    items: Vec<u64>,
+    // '+' or '*'.
+    operator: char,
+    // None stands for "old".
+    operand: Option<u64>,
+    divisible: u64,
+    id_true: usize,
+    id_false: usize,
+}
+
+// Random description of `size.amount` monkeys (at least 2), each starting with up to `size.width` items.
+// Like in real inputs one monkey squares worry levels, a couple multiply them and others add to them.
+// Part one doesn't keep worry levels small, so monkeys are rolled again until 20 rounds of it fit into u64.
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    let amount = size.amount.max(2);
+
+    let mut attempt = 0;
+    let monkeys = loop {
+        attempt += 1;
+        // After many failed attempts only additions are left, those can't overflow.
+        let mut operations: Vec<(char, Option<u64>)> = (0..amount)
+            .map(|i| match i {
+                _ if attempt > 100 => ('+', Some(rng.range(1..=8))),
+                0 => ('*', None),
+                1 | 2 => ('*', Some(rng.range(2..=19))),
+                _ => ('+', Some(rng.range(1..=8))),
+            })
+            .collect();
+        rng.shuffle(&mut operations);
+
+        let monkeys: Vec<MonkeyPlan> = operations
+            .into_iter()
+            .enumerate()
+            .map(|(id, (operator, operand))| {
+                let items = (0..rng.range(1..=size.width.max(1) as u64))
+                    .map(|_| rng.range(50..=99))
+                    .collect();
+                // Items are never thrown to the monkey that holds them,
+                // and both targets are different when there are enough monkeys.
+                let id_true = (id + 1 + rng.index(amount - 1)) % amount;
+                let mut id_false = id_true;
+                while amount > 2 && (id_false == id_true || id_false == id) {
+                    id_false = rng.index(amount);
+                }
+                MonkeyPlan {
+                    items,
+                    operator,
+                    operand,
+                    divisible: *rng.choose(&PRIMES),
+                    id_true,
+                    id_false,
+                }
+            })
+            .collect();
+
+        if fits_part_one(&monkeys) {
+            break monkeys;
+        }
+    };
+
+    let descriptions: Vec<String> = monkeys
+        .iter()
+        .enumerate()
+        .map(|(id, m)| {
+            let items: Vec<String> = m.items.iter().map(|i| i.to_string()).collect();
+            let operand = m.operand.map_or("old".to_string(), |n| n.to_string());
+            format!(
+                "Monkey {id}:\n  Starting items: {}\n  Operation: new = old {} {operand}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                items.join(", "),
+                m.operator,
+                m.divisible,
+                m.id_true,
+                m.id_false
+            )
+        })
+        .collect();
+
+    descriptions.join("\n")
+}
+
+// Does part one finish without worry levels overflowing.
+fn fits_part_one(monkeys: &[MonkeyPlan]) -> bool {
+    let mut items: Vec<Vec<u64>> = monkeys.iter().map(|m| m.items.clone()).collect();
+    for _ in 0..20 {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[i]) {
+                let operand = monkey.operand.unwrap_or(item);
+                let new_value = match monkey.operator {
+                    '+' => item.checked_add(operand),
+                    _ => item.checked_mul(operand),
+                };
+                let Some(new_value) = new_value.map(|v| v / 3) else {
+                    return false;
+                };
+                let target = if new_value.is_multiple_of(monkey.divisible) {
+                    monkey.id_true
+                } else {
+                    monkey.id_false
+                };
+                items[target].push(new_value);
+            }
+        }
+    }
+    true
+}
+
+// Solution of the day for the runner.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Monkey in the Middle";
+    type Input = Input;
+    type Answer1 = Option<usize>;
+    type Answer2 = Option<usize>;
+
+    fn parse(file: &str) -> Result<Input, ParseError> {
+        parse(file)
+    }
+
+    fn part_one(input: &Input) -> Option<usize> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> Option<usize> {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> String {
+        generate(rng, size)
+    }
+}
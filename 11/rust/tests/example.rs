@@ -0,0 +1,136 @@
+// Golden tests against the example input from the puzzle description.
+// Answers here are the ones given in the puzzle text, so they must never change.
+
+const EXAMPLE: &str = include_str!("../../test_input.txt");
+
+#[test]
+fn part_one_example() {
+    let input = day11::parse(EXAMPLE).unwrap();
+    assert_eq!(day11::part_one(&input), Some(10605));
+}
+
+#[test]
+fn part_two_example() {
+    let input = day11::parse(EXAMPLE).unwrap();
+    assert_eq!(day11::part_two(&input), Some(2713310158));
+}
+
+// Worry levels are not reduced at all, but monkeys still decide where to throw the same way.
+#[test]
+fn rounds_without_relief() {
+    let input = day11::parse(EXAMPLE).unwrap();
+    assert_eq!(day11::play(&input, 1, day11::Relief::None), Some(4 * 6));
+    assert_eq!(day11::play(&input, 20, day11::Relief::None), Some(103 * 99));
+}
+
+// Relief that divides by 0 has no answer instead of panicking.
+#[test]
+fn relief_dividing_by_zero() {
+    let input = day11::parse(EXAMPLE).unwrap();
+    assert_eq!(day11::play(&input, 1, day11::Relief::DivideBy(0)), None);
+}
+
+// Counts from the puzzle text after 20 rounds of part one.
+#[test]
+fn inspections_by_monkey() {
+    let input = day11::parse(EXAMPLE).unwrap();
+    let mut game = day11::KeepAway::new(input, day11::Relief::DivideBy(3));
+    for _ in 0..20 {
+        game.round().unwrap();
+    }
+    assert_eq!(
+        game.inspections(),
+        vec![(0, 101), (1, 95), (2, 7), (3, 105)]
+    );
+}
+
+// Worry levels stay below a modulus of more than 32 bits, so squaring one goes past u64 before the modulo.
+#[test]
+fn squares_past_u64() {
+    let file = "Monkey 0:
+  Starting items: 3
+  Operation: new = old * old
+  Test: divisible by 4294967311
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 5
+  Operation: new = old + 1
+  Test: divisible by 2
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+";
+    let input = day11::parse(file).unwrap();
+    assert_eq!(day11::part_one(&input), Some(1520));
+    assert_eq!(day11::part_two(&input), Some(200020000));
+}
+
+// Inputs that would make the game panic are refused by the parser.
+#[test]
+fn broken_monkeys_are_rejected() {
+    let error =
+        day11::parse(&EXAMPLE.replace("throw to monkey 3", "throw to monkey 4")).unwrap_err();
+    assert_eq!((error.line, error.column), (6, 31));
+    assert_eq!(error.expected, "monkey number from 0 to 3");
+
+    let error = day11::parse(&EXAMPLE.replace("divisible by 19", "divisible by 0")).unwrap_err();
+    assert_eq!((error.line, error.column), (11, 22));
+
+    let error = day11::parse(&EXAMPLE.replace("Monkey 2:", "Monkey 3:")).unwrap_err();
+    assert_eq!((error.line, error.column), (15, 8));
+    assert_eq!(error.expected, "monkey number 2");
+
+    let one_monkey = EXAMPLE.split("\n\n").next().unwrap();
+    let error = day11::parse(one_monkey).unwrap_err();
+    assert_eq!(error.expected, "at least two monkeys");
+}
+
+// Part one worry levels are computed in u128 and only have to fit into u64 after dividing by 3.
+// Monkeys that square them past that leave part one without an answer.
+#[test]
+fn part_one_worry_past_u64() {
+    let monkeys = |items: &str, operation: &str| {
+        format!(
+            "Monkey 0:
+  Starting items: {items}
+  Operation: new = old {operation}
+  Test: divisible by 2
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 1
+  Operation: new = old + 2
+  Test: divisible by 3
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+"
+        )
+    };
+
+    let input = day11::parse(&monkeys("18446744073709551615", "+ 1")).unwrap();
+    assert_eq!(day11::part_one(&input), Some(1560));
+
+    let input = day11::parse(&monkeys("8589934592", "* old")).unwrap();
+    assert_eq!(day11::part_one(&input), None);
+    let input = day11::parse(&monkeys("2", "* old")).unwrap();
+    assert_eq!(
+        day11::play(&input, 20, day11::Relief::DivideBy(1)),
+        None,
+        "squaring without relief can't fit"
+    );
+}
+
+// Windows line endings, a byte order mark, spaces at the ends of lines and missing or extra blank lines
+// don't change anything.
+#[test]
+fn messy_example() {
+    let expected = day11::part_two(&day11::parse(EXAMPLE).unwrap());
+    let crlf = format!("\u{feff}{}", EXAMPLE.replace('\n', "  \r\n"));
+    let spaced = format!("\n\n{}\n\n\n", EXAMPLE.replace("\n\n", "\n \n\n"));
+    for messy in [crlf, spaced, EXAMPLE.trim_end().to_string()] {
+        let input = day11::parse(&messy).unwrap();
+        assert_eq!(day11::part_two(&input), expected, "{messy:?}");
+    }
+}
@@ -0,0 +1,158 @@
+// Day 12: Hill Climbing Algorithm.
+use common::graph::{astar, bfs, Graph};
+use common::{Grid, ParseError, Point2, Rng, Size, Solution};
+
+// Puzzle day number.
+pub const DAY: u8 = 12;
+
+// Whole height map with start and end points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeightMap {
+    // Height of every point on the map, from 0 for 'a' to 25 for 'z'.
+    pub heights: Grid<u32>,
+    // Position of starting point.
+    pub start: (usize, usize),
+    // Position of end point.
+    pub end: (usize, usize),
+}
+
+pub type Input = HeightMap;
+
+// Parse input into a height map.
+pub fn parse(file: &str) -> Result<Input, ParseError> {
+    // Treat each character as a point and each line as a row of points.
+    let map = Grid::parse(DAY, file, "height letter a-z, 'S' or 'E'", |c| match c {
+        'S' | 'E' | 'a'..='z' => Some(c),
+        _ => None,
+    })?;
+
+    // Find start and end points.
+    let find = |marker: char| map.iter().find(|(_, c)| **c == marker).map(|(p, _)| p);
+    let start = find('S').ok_or_else(|| ParseError::at_end(DAY, file, "start point 'S'"))?;
+    let end = find('E').ok_or_else(|| ParseError::at_end(DAY, file, "end point 'E'"))?;
+
+    // Start has the lowest height and end has the highest one.
+    let heights = map.map(|c| match c {
+        'S' => 0,
+        'E' => 25,
+        c => (*c as u32) - 97,
+    });
+
+    Ok(HeightMap {
+        heights,
+        start,
+        end,
+    })
+}
+
+// Moves allowed on the map: one step up, down, left or right,
+// climbing at most one height up, while going down any amount is fine.
+impl Graph for HeightMap {
+    type Node = (usize, usize);
+
+    fn neighbours(&self, (x, y): (usize, usize)) -> impl Iterator<Item = ((usize, usize), u64)> {
+        let height = self.heights[(x, y)];
+        self.heights
+            .neighbours_4(x, y)
+            .filter(move |n| self.heights[*n] <= height + 1)
+            .map(|n| (n, 1))
+    }
+}
+
+// Fewest steps from S to E.
+pub fn part_one(map: &Input) -> Option<usize> {
+    // Every step moves one point closer at most, so Manhattan distance never overestimates.
+    let end = Point2::from(map.end);
+    let path = astar(map, [map.start], map.end, |p| {
+        Point2::from(p).manhattan(end)
+    })?;
+
+    Some(path.steps())
+}
+
+// Fewest steps from any lowest point to E.
+pub fn part_two(map: &Input) -> Option<usize> {
+    // Find all points with zero height. Those are our starting points.
+    // Breadth first search from all of them at once takes just one pass over the map.
+    let starting_points = map
+        .heights
+        .iter()
+        .filter(|(_, height)| **height == 0)
+        .map(|(position, _)| position);
+    let path = bfs(map, starting_points, |p| p == map.end)?;
+
+    Some(path.steps())
+}
+
+// Random height map of `size.width` by `size.height` points with a path from S to E, a map needs at least 26 points
+// for that. Path goes from the left column to the right one, climbing one height at a time, other points are random.
+pub fn generate(rng: &mut Rng, size: Size) -> String {
+    let height = size.height.max(1);
+    let width = size.width.max(2).max(26usize.div_ceil(height));
+
+    // Path through every column, going up or down inside of it before moving right, so it never crosses itself.
+    // Snake through whole rows instead if that's too short to climb from 'a' to 'z'.
+    let mut path = Vec::new();
+    let mut y = rng.index(height);
+    for x in 0..width {
+        let target = rng.index(height);
+        path.push((x, y));
+        while y != target {
+            y = if target > y { y + 1 } else { y - 1 };
+            path.push((x, y));
+        }
+    }
+    if path.len() < 26 {
+        path = (0..width)
+            .flat_map(|x| {
+                (0..height).map(move |y| (x, if x % 2 == 0 { y } else { height - 1 - y }))
+            })
+            .collect();
+    }
+
+    let mut map: Vec<Vec<char>> = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| char::from(b'a' + rng.below(26) as u8))
+                .collect()
+        })
+        .collect();
+    // Heights along the path grow evenly from 'a' to 'z', never by more than one.
+    let last = path.len() - 1;
+    for (i, (x, y)) in path.iter().enumerate() {
+        map[*y][*x] = char::from(b'a' + (i * 25 / last) as u8);
+    }
+    map[path[0].1][path[0].0] = 'S';
+    map[path[last].1][path[last].0] = 'E';
+
+    map.into_iter()
+        .map(|row| row.into_iter().chain(['\n']).collect::<String>())
+        .collect()
+}
+
+// Solution of the day for the runner.
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Hill Climbing Algorithm";
+    type Input = Input;
+    type Answer1 = Option<usize>;
+    type Answer2 = Option<usize>;
+
+    fn parse(file: &str) -> Result<Input, ParseError> {
+        parse(file)
+    }
+
+    fn part_one(input: &Input) -> Option<usize> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Input) -> Option<usize> {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut Rng, size: Size) -> String {
+        generate(rng, size)
+    }
+}
@@ -0,0 +1,195 @@
+use crate::output;
+use crate::pool;
+use common::input::InputSource;
+use common::{Day, Part, Run};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+// What happened when a part was run on one file.
+#[derive(Debug)]
+pub enum Outcome {
+    Solved(Run),
+    // File couldn't be read, like a directory or not UTF-8 text.
+    Unreadable(String),
+    // Solver didn't understand the file.
+    ParseFailed(String),
+    // Solver gave up in the middle, input is probably something it doesn't expect.
+    Panicked(String),
+}
+
+impl Outcome {
+    fn status(&self) -> &'static str {
+        match self {
+            Outcome::Solved(_) => "ok",
+            Outcome::Unreadable(_) => "UNREADABLE",
+            Outcome::ParseFailed(_) => "PARSE",
+            Outcome::Panicked(_) => "PANIC",
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Outcome::Solved(_))
+    }
+}
+
+// One file and part of the batch.
+pub struct Entry {
+    pub file: PathBuf,
+    pub part: Part,
+    pub outcome: Outcome,
+}
+
+// Every file directly inside of a directory, in order of names. Hidden files are left out.
+pub fn files_in(dir: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let entries = std::fs::read_dir(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if !hidden && entry.file_type()?.is_file() {
+            files.push(entry.path());
+        }
+    }
+    files.sort();
+
+    if files.is_empty() {
+        return Err(format!("{}: no input files found.", dir.display()).into());
+    }
+    Ok(files)
+}
+
+// Run parts of a day on every file, `jobs` of them at once. Results are in order of files, then parts.
+pub fn run(day: &dyn Day, parts: &[Part], files: &[PathBuf], jobs: usize) -> Vec<Entry> {
+    let tasks: Vec<(&PathBuf, Part)> = files
+        .iter()
+        .flat_map(|file| parts.iter().map(move |part| (file, *part)))
+        .collect();
+
+    let closures: Vec<_> = tasks
+        .iter()
+        .map(|(file, part)| {
+            move || {
+                let input = InputSource::File(file.to_path_buf())
+                    .read()
+                    .map_err(|e| Outcome::Unreadable(e.to_string()))?;
+                day.run(*part, &input)
+                    .map_err(|e| Outcome::ParseFailed(e.to_string()))
+            }
+        })
+        .collect();
+
+    tasks
+        .iter()
+        .zip(pool::run(jobs, closures))
+        .map(|((file, part), result)| Entry {
+            file: file.to_path_buf(),
+            part: *part,
+            outcome: match result {
+                Ok(Ok(run)) => Outcome::Solved(run),
+                Ok(Err(outcome)) => outcome,
+                Err(panic) => Outcome::Panicked(panic),
+            },
+        })
+        .collect()
+}
+
+// Table with one line per file and part, then details of every failure.
+pub fn print_report(day: &dyn Day, entries: &[Entry]) {
+    // File names are shown relative to the batch directory, so they are short enough for a table.
+    let names: Vec<String> = entries
+        .iter()
+        .map(|e| match e.file.file_name() {
+            Some(name) => name.to_string_lossy().into_owned(),
+            None => e.file.display().to_string(),
+        })
+        .collect();
+    let width = names.iter().map(|n| n.len()).max().unwrap_or(0).max(4);
+
+    println!(
+        "{:<width$} {:<4} {:<10} {:<20} {:>12} {:>12}",
+        "File", "Part", "Status", "Answer", "Parse", "Solve"
+    );
+    for (entry, name) in entries.iter().zip(&names) {
+        let (answer, parse, solve) = match &entry.outcome {
+            Outcome::Solved(run) => (
+                output::one_line_answer(day, entry.part, run),
+                format!("{:.2?}", run.parse_time),
+                format!("{:.2?}", run.solve_time),
+            ),
+            _ => (String::new(), String::new(), String::new()),
+        };
+        println!(
+            "{:<width$} {:<4} {:<10} {:<20} {:>12} {:>12}",
+            name,
+            entry.part.number(),
+            entry.outcome.status(),
+            answer,
+            parse,
+            solve
+        );
+    }
+
+    for entry in entries {
+        let message = match &entry.outcome {
+            Outcome::Solved(_) => continue,
+            Outcome::Unreadable(e) | Outcome::ParseFailed(e) | Outcome::Panicked(e) => e,
+        };
+        println!("\n{}, part {}: {message}", entry.file.display(), entry.part);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn every_file_is_run_and_failures_are_flagged() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.txt"), "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n").unwrap();
+        std::fs::write(dir.join("b.txt"), "bvwbjplbgvbhsrlpgdmjqwftvncz\n").unwrap();
+        std::fs::write(dir.join("c.txt"), [0xff, 0xfe]).unwrap();
+        std::fs::write(dir.join(".hidden"), "").unwrap();
+
+        let files = files_in(&dir).unwrap();
+        let day = days::find(6).unwrap();
+        let entries = run(day, &[Part::One, Part::Two], &files, 2);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let answers: Vec<Option<String>> = entries
+            .iter()
+            .map(|e| match &e.outcome {
+                Outcome::Solved(run) => Some(run.answer.to_string()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            answers,
+            [
+                Some("7".to_string()),
+                Some("19".to_string()),
+                Some("5".to_string()),
+                Some("23".to_string()),
+                None,
+                None
+            ]
+        );
+        assert!(matches!(entries[4].outcome, Outcome::Unreadable(_)));
+    }
+
+    #[test]
+    fn parse_failures_are_not_fatal() {
+        let day = days::find(12).unwrap();
+        let dir = std::env::temp_dir().join(format!("aoc-batch-parse-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("broken.txt"), "Sab\nc?E\n").unwrap();
+
+        let entries = run(day, &[Part::One], &files_in(&dir).unwrap(), 1);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(entries[0].outcome, Outcome::ParseFailed(_)));
+        assert!(entries[0].outcome.is_failure());
+    }
+}
@@ -0,0 +1,201 @@
+use common::input::InputSource;
+use common::{Day, Part};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+// Parts this fast are mostly noise, so they are never reported as regressed.
+const NOISE_NS: u64 = 1_000;
+
+// Median timings of one part, in nanoseconds so they survive a trip through TOML.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+}
+
+impl Timing {
+    fn new(parse_time: Duration, solve_time: Duration) -> Self {
+        Self {
+            parse_ns: parse_time.as_nanos() as u64,
+            solve_ns: solve_time.as_nanos() as u64,
+        }
+    }
+}
+
+// Stored timings of every benchmarked part, keyed by day ("01") and then by part ("1").
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline(BTreeMap<String, BTreeMap<String, Timing>>);
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let file = std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        toml::from_str(&file).map_err(|e| format!("{}: {e}", path.display()).into())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, toml::to_string(self)?)
+            .map_err(|e| format!("{}: {e}", path.display()).into())
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<Timing> {
+        self.0
+            .get(&format!("{day:02}"))
+            .and_then(|parts| parts.get(&part.to_string()))
+            .copied()
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, timing: Timing) {
+        self.0
+            .entry(format!("{day:02}"))
+            .or_default()
+            .insert(part.to_string(), timing);
+    }
+}
+
+// Where baseline with this name is stored. Timings depend on the machine, so they live in target directory.
+pub fn baseline_path(name: &str) -> PathBuf {
+    let target = match std::env::var_os("CARGO_TARGET_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join("target"),
+    };
+    target.join("aoc-bench").join(format!("{name}.toml"))
+}
+
+// Benchmark of one part of a day.
+pub struct Bench {
+    pub day: u8,
+    pub part: Part,
+    pub timing: Timing,
+}
+
+// Parse and solve one part `iterations` times and keep median of each phase.
+// Input is read once, so disk doesn't show up in timings.
+pub fn bench(
+    day: &dyn Day,
+    part: Part,
+    iterations: usize,
+    inputs_dir: Option<&Path>,
+) -> Result<Bench, Box<dyn Error>> {
+    let file = InputSource::new(day.day(), None, inputs_dir).read()?;
+
+    // Warm up caches and allocator before measuring.
+    day.run(part, &file)?;
+
+    let mut parse_times = Vec::with_capacity(iterations);
+    let mut solve_times = Vec::with_capacity(iterations);
+    for _ in 0..iterations.max(1) {
+        let run = day.run(part, &file)?;
+        parse_times.push(run.parse_time);
+        solve_times.push(run.solve_time);
+    }
+
+    Ok(Bench {
+        day: day.day(),
+        part,
+        timing: Timing::new(median(&mut parse_times), median(&mut solve_times)),
+    })
+}
+
+fn median(times: &mut [Duration]) -> Duration {
+    times.sort();
+    times[times.len() / 2]
+}
+
+// Change from baseline to current timing in percents.
+fn change(baseline_ns: u64, current_ns: u64) -> f64 {
+    if baseline_ns == 0 {
+        return 0.0;
+    }
+    (current_ns as f64 - baseline_ns as f64) / baseline_ns as f64 * 100.0
+}
+
+// Print a table of timings. With baseline also print changes and mark parts that got slower than threshold.
+// Returns amount of regressed parts.
+pub fn print_report(benches: &[Bench], baseline: Option<&Baseline>, threshold: f64) -> usize {
+    let mut regressions = 0;
+
+    let mut header = format!("{:<4} {:<4} {:>12} {:>12}", "Day", "Part", "Parse", "Solve");
+    if baseline.is_some() {
+        header += &format!(" {:>9} {:>9}", "Parse Δ", "Solve Δ");
+    }
+    println!("{header}");
+    for b in benches {
+        let parse = Duration::from_nanos(b.timing.parse_ns);
+        let solve = Duration::from_nanos(b.timing.solve_ns);
+        let mut line = format!(
+            "{:<4} {:<4} {:>12} {:>12}",
+            format!("{:02}", b.day),
+            b.part.number(),
+            format!("{parse:.2?}"),
+            format!("{solve:.2?}"),
+        );
+
+        match baseline.and_then(|base| base.get(b.day, b.part)) {
+            Some(old) => {
+                let parse_change = change(old.parse_ns, b.timing.parse_ns);
+                let solve_change = change(old.solve_ns, b.timing.solve_ns);
+                line += &format!(" {parse_change:>+8.1}% {solve_change:>+8.1}%");
+                let regressed = |old_ns: u64, new_ns: u64, change: f64| {
+                    change > threshold && new_ns.saturating_sub(old_ns) > NOISE_NS
+                };
+                if regressed(old.parse_ns, b.timing.parse_ns, parse_change)
+                    || regressed(old.solve_ns, b.timing.solve_ns, solve_change)
+                {
+                    regressions += 1;
+                    line += "  regressed";
+                }
+            }
+            None if baseline.is_some() => line += &format!(" {:>9} {:>9}", "new", "new"),
+            None => (),
+        }
+        println!("{line}");
+    }
+
+    let total: u64 = benches
+        .iter()
+        .map(|b| b.timing.parse_ns + b.timing.solve_ns)
+        .sum();
+    println!("\nTotal of medians: {:.2?}", Duration::from_nanos(total));
+    if baseline.is_some() {
+        println!("{regressions} part(s) slower than {threshold}% over baseline.");
+    }
+
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn baseline_round_trip() {
+        let mut baseline = Baseline::default();
+        let timing = Timing {
+            parse_ns: 1200,
+            solve_ns: 3400,
+        };
+        baseline.insert(1, Part::Two, timing);
+
+        let saved = toml::to_string(&baseline).unwrap();
+        let loaded: Baseline = toml::from_str(&saved).unwrap();
+
+        assert_eq!(loaded.get(1, Part::Two), Some(timing));
+        assert_eq!(loaded.get(1, Part::One), None);
+    }
+
+    #[test]
+    fn change_in_percents() {
+        assert_eq!(change(100, 150), 50.0);
+        assert_eq!(change(200, 100), -50.0);
+        assert_eq!(change(0, 100), 0.0);
+    }
+}
@@ -0,0 +1,98 @@
+use common::Day;
+
+// Every day that has a solution, in order.
+pub static DAYS: [&dyn Day; 12] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+];
+
+// Find a day by its number.
+pub fn find(number: u8) -> Option<&'static dyn Day> {
+    DAYS.iter().find(|d| d.day() == number).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{Answer, Part, Size};
+
+    // Generated inputs must be valid: they parse, and every part has an answer without panicking.
+    #[test]
+    fn generated_inputs_are_solved() {
+        let sizes = [
+            Size {
+                amount: 0,
+                width: 0,
+                height: 0,
+            },
+            Size {
+                amount: 12,
+                width: 30,
+                height: 8,
+            },
+        ];
+        for day in DAYS {
+            for seed in 0..5 {
+                for size in sizes {
+                    let input = day.generate(seed, size);
+                    assert_eq!(input, day.generate(seed, size), "day {}", day.day());
+
+                    for part in Part::ALL {
+                        let run = day.run(part, &input).unwrap_or_else(|e| {
+                            panic!("day {}, seed {seed}, {size:?}: {e}\n{input}", day.day())
+                        });
+                        assert_ne!(run.answer, Answer::None, "day {}\n{input}", day.day());
+                    }
+                }
+            }
+        }
+    }
+
+    // Days that can read input bit by bit must give the same answers as when they parse all of it.
+    #[test]
+    fn streamed_answers_match() {
+        let size = Size {
+            amount: 50,
+            width: 20,
+            height: 20,
+        };
+        let mut streamed = Vec::new();
+        for day in DAYS {
+            for seed in 0..3 {
+                let input = day.generate(seed, size);
+                for part in Part::ALL {
+                    let Some(run) = day.run_streaming(part, &mut input.as_bytes()) else {
+                        continue;
+                    };
+                    let expected = day.run(part, &input).unwrap().answer;
+                    assert_eq!(run.unwrap().answer, expected, "day {}\n{input}", day.day());
+                    streamed.push(day.day());
+                }
+            }
+        }
+        streamed.dedup();
+        assert_eq!(streamed, [1, 2, 3, 4, 6]);
+    }
+
+    #[test]
+    fn generated_markers_are_at_the_end() {
+        let size = Size {
+            amount: 500,
+            ..Size::default()
+        };
+        let run = find(6)
+            .unwrap()
+            .run(Part::Two, &find(6).unwrap().generate(3, size));
+        assert_eq!(run.unwrap().answer, Answer::Integer(500));
+    }
+}
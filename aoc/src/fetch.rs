@@ -0,0 +1,273 @@
+use crate::store::{Integrity, Profile};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Year of puzzles solved in this repository.
+const YEAR: u16 = 2022;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+// File inside of profiles directory with the time of the last request, shared by every profile.
+const LAST_REQUEST_FILE: &str = ".last-fetch";
+// Puzzle authors ask automated tools to say who they are.
+const USER_AGENT: &str = "aoc-2022-runner (downloads each input once and caches it)";
+
+// Downloads inputs from the puzzle site, or anything that answers the same way.
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+    // Shortest time between two requests, even from different runs.
+    min_interval: Duration,
+}
+
+// What fetching did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    // Input was already in the store and untouched, nothing was downloaded.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: &str, min_interval: Duration) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            min_interval,
+        }
+    }
+
+    // Put input of a day into a profile. Stored input is used as long as it matches its checksum,
+    // `force` downloads it again and replaces whatever is stored.
+    pub fn fetch_into(
+        &self,
+        profile: &mut Profile,
+        day: u8,
+        force: bool,
+    ) -> Result<Fetched, Box<dyn Error>> {
+        if !force && profile.check(day) == Integrity::Ok {
+            return Ok(Fetched::Cached(profile.input_path(day)));
+        }
+
+        let stamp = profile
+            .dir
+            .parent()
+            .unwrap_or(&profile.dir)
+            .join(LAST_REQUEST_FILE);
+        wait_for_turn(&stamp, self.min_interval)?;
+
+        let content = self.download(day)?;
+        Ok(Fetched::Downloaded(profile.add_content(
+            day,
+            content.as_bytes(),
+            force,
+        )?))
+    }
+
+    // Input of a day, exactly as the site sends it.
+    fn download(&self, day: u8) -> Result<String, Box<dyn Error>> {
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call();
+
+        match response {
+            Ok(response) => {
+                let body = response.into_string()?;
+                if body.is_empty() {
+                    return Err(format!("{url}: got an empty input.").into());
+                }
+                Ok(body)
+            }
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(status_error(day, status, &body).into())
+            }
+            // Transport errors already mention the address.
+            Err(ureq::Error::Transport(e)) => Err(e.to_string().into()),
+        }
+    }
+}
+
+// Explain an error response. Site answers with 400 and a request to log in when the session is wrong or expired.
+fn status_error(day: u8, status: u16, body: &str) -> String {
+    if matches!(status, 400 | 401 | 403) || body.to_lowercase().contains("log in") {
+        "Session cookie was rejected, it's probably expired. Log in again and update AOC_SESSION."
+            .to_string()
+    } else if status == 404 {
+        format!("Input of day {day:02} is not available, the puzzle may not be unlocked yet.")
+    } else {
+        format!(
+            "Downloading day {day:02} input failed with status {status}: {}",
+            body.trim()
+        )
+    }
+}
+
+// Sleep until `min_interval` has passed since the last request, then record a new one.
+fn wait_for_turn(stamp: &Path, min_interval: Duration) -> Result<(), Box<dyn Error>> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+    let last = std::fs::read_to_string(stamp)
+        .ok()
+        .and_then(|s| s.trim().parse().ok())
+        .map(Duration::from_millis);
+
+    let wait = time_to_wait(last, now, min_interval);
+    if !wait.is_zero() {
+        println!("Waiting {wait:.1?} before the next request...");
+        std::thread::sleep(wait);
+    }
+
+    if let Some(parent) = stamp.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+    std::fs::write(stamp, now.as_millis().to_string())?;
+    Ok(())
+}
+
+// How long to wait before a request. Last request from the future (clock went back) doesn't block forever.
+fn time_to_wait(last: Option<Duration>, now: Duration, min_interval: Duration) -> Duration {
+    match last {
+        Some(last) if last <= now => min_interval.saturating_sub(now - last),
+        _ => Duration::ZERO,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    const INPUT: &str = "1000\n2000\n\n3000\n";
+
+    // Stand-in for the puzzle site on a local port. Knows only day 1 and only one session.
+    // Returns its base URL and a counter of requests it got.
+    fn stub_server() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let counter = Arc::clone(&requests);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let mut lines = BufReader::new(&stream).lines().map_while(Result::ok);
+                let request = lines.next().unwrap_or_default();
+                let headers: Vec<String> = lines.take_while(|l| !l.is_empty()).collect();
+                let logged_in = headers.iter().any(|h| h == "Cookie: session=good");
+
+                let (status, body) = match (request.split(' ').nth(1), logged_in) {
+                    (Some("/2022/day/1/input"), true) => ("200 OK", INPUT),
+                    (Some("/2022/day/1/input"), false) => (
+                        "400 Bad Request",
+                        "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+                    ),
+                    _ => ("404 Not Found", "404 Not Found\n"),
+                };
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn downloads_once_then_uses_cache() {
+        let (base_url, requests) = stub_server();
+        let dir = temp_dir("cache");
+        let mut profile = Profile::open(Some(&dir), "alice").unwrap();
+        let fetcher = Fetcher::new(&base_url, "good\n", Duration::ZERO);
+
+        let path = profile.input_path(1);
+        assert_eq!(
+            fetcher.fetch_into(&mut profile, 1, false).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), INPUT);
+        assert_eq!(
+            fetcher.fetch_into(&mut profile, 1, false).unwrap(),
+            Fetched::Cached(path.clone())
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        // Edited input is restored from the site.
+        std::fs::write(&path, INPUT.trim_end()).unwrap();
+        assert_eq!(
+            fetcher.fetch_into(&mut profile, 1, false).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(profile.check(1), Integrity::Ok);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn expired_session_and_locked_day() {
+        let (base_url, _) = stub_server();
+        let dir = temp_dir("errors");
+        let mut profile = Profile::open(Some(&dir), "bob").unwrap();
+
+        let expired = Fetcher::new(&base_url, "old", Duration::ZERO);
+        let e = expired.fetch_into(&mut profile, 1, false).unwrap_err();
+        assert!(e.to_string().contains("expired"), "{e}");
+
+        let fetcher = Fetcher::new(&base_url, "good", Duration::ZERO);
+        let e = fetcher.fetch_into(&mut profile, 25, false).unwrap_err();
+        assert!(e.to_string().contains("not be unlocked"), "{e}");
+        assert_eq!(profile.check(1), Integrity::Missing);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn requests_are_rate_limited() {
+        let (base_url, _) = stub_server();
+        let dir = temp_dir("rate");
+        let mut profile = Profile::open(Some(&dir), "carol").unwrap();
+        let fetcher = Fetcher::new(&base_url, "good", Duration::from_millis(300));
+
+        let start = Instant::now();
+        fetcher.fetch_into(&mut profile, 1, true).unwrap();
+        fetcher.fetch_into(&mut profile, 1, true).unwrap();
+        // Time of the last request is stored in whole milliseconds and wall clock, some slack is needed.
+        assert!(start.elapsed() >= Duration::from_millis(290));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn waiting_time() {
+        let second = Duration::from_secs(1);
+        assert_eq!(time_to_wait(None, second * 10, second * 5), Duration::ZERO);
+        assert_eq!(
+            time_to_wait(Some(second * 8), second * 10, second * 5),
+            second * 3
+        );
+        assert_eq!(
+            time_to_wait(Some(second), second * 10, second * 5),
+            Duration::ZERO
+        );
+        assert_eq!(
+            time_to_wait(Some(second * 20), second * 10, second * 5),
+            Duration::ZERO
+        );
+    }
+}
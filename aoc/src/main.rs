@@ -0,0 +1,612 @@
+use clap::{Parser, Subcommand};
+use common::input::InputSource;
+use common::{Day, Part, Run, Size};
+use output::{Format, Record};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+mod batch;
+mod bench;
+mod days;
+#[cfg(feature = "fetch")]
+mod fetch;
+mod output;
+mod pool;
+mod store;
+mod verify;
+mod watch;
+
+// Command line interface of the runner.
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions runner")]
+struct Cli {
+    /// Directory with NN/input.txt files. Defaults to $AOC_INPUTS or the repository root.
+    #[arg(long, global = true)]
+    inputs: Option<PathBuf>,
+    /// Use inputs of this profile from the input store instead of the loose NN/input.txt files.
+    #[arg(long, global = true, env = "AOC_PROFILE")]
+    profile: Option<String>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one part of a day, or every solved part with --all.
+    Run {
+        /// Day number.
+        #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Part number.
+        #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file to use instead of the default one, "-" reads from stdin.
+        #[arg(short, long, conflicts_with = "all")]
+        input: Option<PathBuf>,
+        /// Run every solved day and part.
+        #[arg(long, conflicts_with_all = ["day", "part"])]
+        all: bool,
+        /// Run parts on this many threads at once and print a summary table. Needs --all.
+        #[arg(short, long, requires = "all", value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: Option<u16>,
+        /// Output format. JSON and CSV hold day, part, answer, parse and solve times of every part.
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Read input bit by bit instead of loading all of it, for inputs that don't fit into memory.
+        /// Only days 01 to 04 and 06 can do it. Parse time is counted as solve time.
+        #[arg(long, conflicts_with = "all")]
+        stream: bool,
+    },
+    /// List every day and its solved parts.
+    List,
+    /// Measure parse and solve times of every solved part on real inputs.
+    Bench {
+        /// Only benchmark this day.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// How many times each part is run, median is reported.
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+        /// Save timings as a baseline with this name.
+        #[arg(long, value_name = "NAME")]
+        save_baseline: Option<String>,
+        /// Compare timings with a saved baseline.
+        #[arg(long, value_name = "NAME")]
+        baseline: Option<String>,
+        /// Slowdown in percents over baseline that counts as a regression.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Run every solved part on real inputs and compare answers with the recorded ones.
+    Verify {
+        /// Only verify this day.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Answers file. Defaults to answers.toml inside of inputs directory.
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Manage the input store, which keeps inputs of each account in its own profile.
+    #[command(subcommand)]
+    Input(InputCommand),
+    /// Download input of a day into a profile (picked with --profile) of the input store.
+    #[cfg(feature = "fetch")]
+    Fetch {
+        /// Day number.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Value of the "session" cookie of a logged in browser.
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: String,
+        /// Address of the puzzle site.
+        #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+        /// Shortest time between two requests to the site, in seconds.
+        #[arg(long, default_value_t = 5)]
+        min_interval: u64,
+        /// Download the input even if it's already stored, replacing it.
+        #[arg(long)]
+        force: bool,
+    },
+    /// Run a day on every file in a directory, like inputs of other people, and print a table of answers.
+    Batch {
+        /// Day number.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Directory with input files.
+        dir: PathBuf,
+        /// Only run this part, every solved part is run otherwise.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Run this many files at once.
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: u16,
+    },
+    /// Print a random valid input of a day, for finding out how solutions cope with big inputs.
+    Generate {
+        /// Day number.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Same seed and sizes always give the same input.
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+        /// Amount of main things in the input: elves, rounds, rucksacks, pairs, moves, characters, directories,
+        /// motions, instructions or monkeys.
+        #[arg(short = 'n', long, default_value_t = Size::default().amount)]
+        amount: usize,
+        /// Width of tree and height maps. Longest motion for day 09, amount of stacks for day 05,
+        /// items per elf, rucksack compartment, monkey or directory for others, highest section for day 04.
+        #[arg(long, default_value_t = Size::default().width)]
+        width: usize,
+        /// Height of tree and height maps. Highest starting stack for day 05.
+        #[arg(long, default_value_t = Size::default().height)]
+        height: usize,
+        /// Write the input to this file instead of standard output.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Rank elves of day 01 by calories they carry, elves with equal totals are listed separately.
+    Calories {
+        /// Input file to use instead of the default one, "-" reads from stdin.
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// How many elves to rank.
+        #[arg(long, default_value_t = 3)]
+        top: usize,
+        /// Report statistics of all elves instead: totals, mean, median, percentiles, items and a histogram.
+        #[arg(long, conflicts_with = "top")]
+        stats: bool,
+    },
+    /// Run a day again every time its input or source changes, showing how answers and timings changed.
+    Watch {
+        /// Day number.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Only run this part, both solved parts are run otherwise.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input files to watch. Defaults to the real input and NN/test_input.txt next to it.
+        #[arg(short, long)]
+        input: Vec<PathBuf>,
+        /// How often files are checked for changes, in milliseconds.
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+}
+
+#[derive(Subcommand)]
+enum InputCommand {
+    /// Copy an input into a profile (picked with --profile) and record its checksum.
+    Add {
+        /// Day number.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Input file.
+        file: PathBuf,
+        /// Replace an input that was added before with different content.
+        #[arg(long)]
+        force: bool,
+    },
+    /// List stored inputs of every profile, or only of the one picked with --profile, and check their checksums.
+    List,
+}
+
+// Run one part of a day and get its answer with timings.
+fn run_part(day: &dyn Day, part: Part, input: &InputSource) -> Result<Run, Box<dyn Error>> {
+    if !day.has_part(part) {
+        return Err(format!("Day {:02} part {part} is not solved yet.", day.day()).into());
+    }
+    let file = input.read()?;
+
+    Ok(day.run(part, &file)?)
+}
+
+// Same as run_part, but input is read bit by bit while solving.
+fn stream_part(day: &dyn Day, part: Part, input: &InputSource) -> Result<Run, Box<dyn Error>> {
+    if !day.has_part(part) {
+        return Err(format!("Day {:02} part {part} is not solved yet.", day.day()).into());
+    }
+    let mut reader = input.open()?;
+
+    match day.run_streaming(part, &mut reader) {
+        Some(run) => Ok(run?),
+        None => Err(format!(
+            "Day {:02} needs whole input at once, it can't be streamed.",
+            day.day()
+        )
+        .into()),
+    }
+}
+
+// Run every task on a thread pool. Failure or panic of one part doesn't stop others,
+// all of them are reported at the end, in order of days.
+fn run_parallel(
+    tasks: Vec<(&dyn Day, Part, InputSource)>,
+    jobs: usize,
+    format: Format,
+) -> Result<(), Box<dyn Error>> {
+    let start = Instant::now();
+    let closures: Vec<_> = tasks
+        .iter()
+        .map(|(day, part, input)| {
+            // Errors are turned into strings, boxed errors can't be sent between threads.
+            move || run_part(*day, *part, input).map_err(|e| e.to_string())
+        })
+        .collect();
+    let results = pool::run(jobs, closures);
+    let wall_time = start.elapsed();
+
+    // Pool error is a panic, inner one is a normal error of that part.
+    let outcomes: Vec<(&dyn Day, Part, Result<Run, String>)> = tasks
+        .iter()
+        .zip(results)
+        .map(|((day, part, _), result)| (*day, *part, result.and_then(|r| r)))
+        .collect();
+
+    match format {
+        Format::Text => output::print_summary(&outcomes, wall_time),
+        Format::Json | Format::Csv => {
+            let mut records = Vec::new();
+            for (day, part, result) in &outcomes {
+                match result {
+                    Ok(run) => records.push(Record::new(*day, *part, run)),
+                    Err(e) => eprintln!("Day {:02}, part {part}: {e}", day.day()),
+                }
+            }
+            match format {
+                Format::Json => println!("{}", output::to_json(&records)),
+                _ => print!("{}", output::to_csv(&records)),
+            }
+        }
+    }
+
+    let failed = outcomes.iter().filter(|(_, _, r)| r.is_err()).count();
+    if failed > 0 {
+        return Err(format!("{failed} part(s) failed.").into());
+    }
+
+    Ok(())
+}
+
+// One day if its number is given, every solved day otherwise.
+fn selected_days(day: Option<u8>) -> Result<Vec<&'static dyn Day>, Box<dyn Error>> {
+    match day {
+        Some(n) => {
+            let day = days::find(n).ok_or_else(|| format!("Day {n:02} is not solved yet."))?;
+            Ok(vec![day])
+        }
+        None => Ok(days::DAYS.to_vec()),
+    }
+}
+
+// One part if its number is given, every solved part of the day otherwise.
+fn selected_parts(day: &dyn Day, part: Option<u8>) -> Result<Vec<Part>, Box<dyn Error>> {
+    match part.and_then(Part::from_number) {
+        Some(part) if !day.has_part(part) => {
+            Err(format!("Day {:02} part {part} is not solved yet.", day.day()).into())
+        }
+        Some(part) => Ok(vec![part]),
+        None => Ok(Part::ALL.into_iter().filter(|p| day.has_part(*p)).collect()),
+    }
+}
+
+// Execute parsed command.
+fn execute(cli: Cli) -> Result<(), Box<dyn Error>> {
+    if let Command::Input(command) = cli.command {
+        return execute_input(command, cli.inputs.as_deref(), cli.profile.as_deref());
+    }
+    #[cfg(feature = "fetch")]
+    if let Command::Fetch {
+        day,
+        session,
+        base_url,
+        min_interval,
+        force,
+    } = cli.command
+    {
+        let name = cli
+            .profile
+            .ok_or("Pick a profile to download the input to with --profile.")?;
+        let mut profile = store::Profile::open(cli.inputs.as_deref(), &name)?;
+        let fetcher = fetch::Fetcher::new(&base_url, &session, Duration::from_secs(min_interval));
+        match fetcher.fetch_into(&mut profile, day, force)? {
+            fetch::Fetched::Cached(path) => println!(
+                "Day {day:02} input of profile \"{name}\" is already stored at {}",
+                path.display()
+            ),
+            fetch::Fetched::Downloaded(path) => println!(
+                "Day {day:02} input of profile \"{name}\" downloaded to {}",
+                path.display()
+            ),
+        }
+        return Ok(());
+    }
+
+    // Profile directory takes place of the inputs directory, after making sure nothing in it was edited by accident.
+    let profile_dir = match &cli.profile {
+        Some(name) => {
+            let profile = store::Profile::open_existing(cli.inputs.as_deref(), name)?;
+            profile.verify()?;
+            Some(profile.dir)
+        }
+        None => None,
+    };
+    let inputs_dir = profile_dir.as_deref().or(cli.inputs.as_deref());
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            all,
+            jobs,
+            format,
+            stream,
+        } => {
+            let mut tasks: Vec<(&dyn Day, Part, InputSource)> = Vec::new();
+            if all {
+                // Run every part that has a solution, one after another.
+                for day in days::DAYS {
+                    for part in Part::ALL {
+                        if day.has_part(part) {
+                            tasks.push((day, part, InputSource::new(day.day(), None, inputs_dir)));
+                        }
+                    }
+                }
+            } else {
+                // Both are required by clap when --all is not set.
+                let (day, part) = (day.unwrap(), part.and_then(Part::from_number).unwrap());
+                let day =
+                    days::find(day).ok_or_else(|| format!("Day {day:02} is not solved yet."))?;
+                let input = InputSource::new(day.day(), input.as_deref(), inputs_dir);
+                tasks.push((day, part, input));
+            }
+
+            if let Some(jobs) = jobs {
+                return run_parallel(tasks, jobs.into(), format);
+            }
+
+            // Text is printed as soon as each part is done, other formats need every answer first.
+            let mut records = Vec::new();
+            for (day, part, input) in tasks {
+                let run = if stream {
+                    stream_part(day, part, &input)?
+                } else {
+                    run_part(day, part, &input)?
+                };
+                match format {
+                    Format::Text => output::print_text(day, part, &run),
+                    Format::Json | Format::Csv => records.push(Record::new(day, part, &run)),
+                }
+            }
+            match format {
+                Format::Text => (),
+                Format::Json => println!("{}", output::to_json(&records)),
+                Format::Csv => print!("{}", output::to_csv(&records)),
+            }
+        }
+        Command::Bench {
+            day,
+            iterations,
+            save_baseline,
+            baseline,
+            threshold,
+        } => {
+            // Load baseline before running anything, so a typo in its name doesn't waste a whole run.
+            let baseline = match baseline {
+                Some(name) => Some(bench::Baseline::load(&bench::baseline_path(&name))?),
+                None => None,
+            };
+
+            let mut benches = Vec::new();
+            for day in selected_days(day)? {
+                for part in Part::ALL {
+                    if day.has_part(part) {
+                        benches.push(bench::bench(day, part, iterations, inputs_dir)?);
+                    }
+                }
+            }
+            let regressions = bench::print_report(&benches, baseline.as_ref(), threshold);
+
+            if let Some(name) = save_baseline {
+                let mut saved = bench::Baseline::default();
+                for b in &benches {
+                    saved.insert(b.day, b.part, b.timing);
+                }
+                let path = bench::baseline_path(&name);
+                saved.save(&path)?;
+                println!("Baseline saved to {}", path.display());
+            }
+
+            if regressions > 0 {
+                return Err(format!("{regressions} part(s) regressed.").into());
+            }
+        }
+        Command::Verify { day, answers } => {
+            let answers_path = answers.unwrap_or_else(|| verify::answers_path(inputs_dir));
+            let answers = verify::Answers::load(&answers_path)?;
+
+            let mut checks = Vec::new();
+            for day in selected_days(day)? {
+                for part in Part::ALL {
+                    if day.has_part(part) {
+                        checks.push(verify::check(day, part, &answers, inputs_dir));
+                    }
+                }
+            }
+            verify::print_report(&checks);
+
+            // Mismatches must be noticed by scripts and CI, not only by people reading the table.
+            let failed = checks.iter().filter(|c| c.status.is_failure()).count();
+            if failed > 0 {
+                return Err(format!("{failed} part(s) failed verification.").into());
+            }
+        }
+        Command::Batch {
+            day,
+            dir,
+            part,
+            jobs,
+        } => {
+            let day = days::find(day).ok_or_else(|| format!("Day {day:02} is not solved yet."))?;
+            let parts = selected_parts(day, part)?;
+            let files = batch::files_in(&dir)?;
+
+            let entries = batch::run(day, &parts, &files, jobs.into());
+            batch::print_report(day, &entries);
+
+            let failed = entries.iter().filter(|e| e.outcome.is_failure()).count();
+            if failed > 0 {
+                return Err(format!("{failed} run(s) failed.").into());
+            }
+        }
+        Command::Generate {
+            day,
+            seed,
+            amount,
+            width,
+            height,
+            output,
+        } => {
+            let day = days::find(day).ok_or_else(|| format!("Day {day:02} is not solved yet."))?;
+            let size = Size {
+                amount,
+                width,
+                height,
+            };
+            let input = day.generate(seed, size);
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, input).map_err(|e| format!("{}: {e}", path.display()))?
+                }
+                None => print!("{input}"),
+            }
+        }
+        Command::Watch {
+            day,
+            part,
+            input,
+            interval,
+        } => {
+            let day = days::find(day).ok_or_else(|| format!("Day {day:02} is not solved yet."))?;
+            let parts = selected_parts(day, part)?;
+
+            let inputs = if input.is_empty() {
+                let mut inputs = Vec::new();
+                if let InputSource::File(real) = InputSource::new(day.day(), None, inputs_dir) {
+                    let example = real.with_file_name("test_input.txt");
+                    inputs.push(real);
+                    if example.is_file() {
+                        inputs.push(example);
+                    }
+                }
+                inputs
+            } else {
+                input
+            };
+
+            let watch = watch::Watch {
+                day,
+                parts,
+                inputs,
+                sources: watch::day_sources(day.day()),
+                interval: Duration::from_millis(interval),
+            };
+            watch.run()?;
+        }
+        Command::Calories { input, top, stats } => {
+            let file = InputSource::new(day01::DAY, input.as_deref(), inputs_dir).read()?;
+            if stats {
+                // Statistics add up calories in u64, so they work even where ranking can't parse the input.
+                match day01::stats::stats(&day01::stats::inventories(&file)?) {
+                    Some(stats) => print!("{stats}"),
+                    None => println!("There are no elves!"),
+                }
+            } else {
+                let sums = day01::parse(&file)?;
+                print!("{}", day01::ranking_report(&day01::top_elves(&sums, top)));
+            }
+        }
+        // Handled before inputs directory is picked.
+        Command::Input(_) => unreachable!(),
+        #[cfg(feature = "fetch")]
+        Command::Fetch { .. } => unreachable!(),
+        Command::List => {
+            for day in days::DAYS {
+                let parts: Vec<String> = Part::ALL
+                    .into_iter()
+                    .filter(|p| day.has_part(*p))
+                    .map(|p| p.to_string())
+                    .collect();
+                println!(
+                    "Day {:02}: {} [{}]",
+                    day.day(),
+                    day.title(),
+                    parts.join(", ")
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Execute a command of the input store.
+fn execute_input(
+    command: InputCommand,
+    inputs_dir: Option<&Path>,
+    profile: Option<&str>,
+) -> Result<(), Box<dyn Error>> {
+    match command {
+        InputCommand::Add { day, file, force } => {
+            let name = profile.ok_or("Pick a profile to add the input to with --profile.")?;
+            let mut profile = store::Profile::open(inputs_dir, name)?;
+            let path = profile.add(day, &file, force)?;
+            println!(
+                "Day {day:02} input of profile \"{name}\" stored at {}",
+                path.display()
+            );
+        }
+        InputCommand::List => {
+            let profiles = match profile {
+                Some(name) => vec![store::Profile::open_existing(inputs_dir, name)?],
+                None => store::profiles(inputs_dir)?,
+            };
+            if profiles.is_empty() {
+                println!("Input store is empty, add inputs with `aoc input add <day> <file> --profile <name>`.");
+            }
+
+            let mut broken = 0;
+            for profile in &profiles {
+                println!("Profile {} ({}):", profile.name, profile.dir.display());
+                for day in profile.days() {
+                    let status = match profile.check(day) {
+                        store::Integrity::Ok => "ok".to_string(),
+                        store::Integrity::Missing => "MISSING".to_string(),
+                        store::Integrity::Modified(Some(hint)) => format!("MODIFIED, {hint}"),
+                        store::Integrity::Modified(None) => "MODIFIED".to_string(),
+                    };
+                    if status != "ok" {
+                        broken += 1;
+                    }
+                    println!("  Day {day:02}: {status}");
+                }
+            }
+
+            if broken > 0 {
+                return Err(format!("{broken} input(s) don't match their checksums.").into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() {
+    // Print errors with Display, Debug output of io errors is hard to read.
+    if let Err(e) = execute(Cli::parse()) {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+}
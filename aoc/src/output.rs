@@ -0,0 +1,187 @@
+use common::{Answer, Day, Part, Run};
+use serde::Serialize;
+use std::time::Duration;
+
+// How answers are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    // Human readable text.
+    Text,
+    // JSON array with one object per part.
+    Json,
+    // CSV with a header line and one line per part.
+    Csv,
+}
+
+// One answered part in machine readable form. Field names and types are the same for every day,
+// scripts rely on them, so only add new fields here.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub title: &'static str,
+    // Answer as it would be typed into the puzzle page. Pictures are read as letters,
+    // null if there is no answer or the picture couldn't be read.
+    pub answer: Option<String>,
+    // Rows of a picture answer, like day 10 screen, null for other answers.
+    pub rows: Option<Vec<String>>,
+    pub parse_time_ns: u64,
+    pub solve_time_ns: u64,
+}
+
+impl Record {
+    pub fn new(day: &dyn Day, part: Part, run: &Run) -> Self {
+        let (answer, rows) = match &run.answer {
+            Answer::Bitmap(b) => (b.letters(), Some(b.rows())),
+            Answer::None => (None, None),
+            answer => (Some(answer.to_string()), None),
+        };
+
+        Self {
+            day: day.day(),
+            part: part.number(),
+            title: day.title(),
+            answer,
+            rows,
+            parse_time_ns: run.parse_time.as_nanos() as u64,
+            solve_time_ns: run.solve_time.as_nanos() as u64,
+        }
+    }
+}
+
+// Print answer of one part as text, right away.
+pub fn print_text(day: &dyn Day, part: Part, run: &Run) {
+    println!(
+        "Day {:02}, part {part}: {} [parse: {:?}, solve: {:?}]",
+        day.day(),
+        day.title(),
+        run.parse_time,
+        run.solve_time
+    );
+    println!("{}", run.answer);
+}
+
+// Answer that fits into a table cell. Pictures are shown as letters, or by their first row if they can't be read.
+pub fn one_line_answer(day: &dyn Day, part: Part, run: &Run) -> String {
+    let answer = Record::new(day, part, run).answer;
+    let answer = answer.unwrap_or_else(|| run.answer.to_string());
+    answer.lines().next().unwrap_or("").to_string()
+}
+
+// Table with one line per part, then details of failed parts and total time it all took.
+pub fn print_summary(outcomes: &[(&dyn Day, Part, Result<Run, String>)], wall_time: Duration) {
+    println!(
+        "{:<4} {:<4} {:<6} {:<20} {:>12} {:>12}",
+        "Day", "Part", "Status", "Answer", "Parse", "Solve"
+    );
+    for (day, part, result) in outcomes {
+        let (status, answer, parse, solve) = match result {
+            Ok(run) => {
+                let answer = one_line_answer(*day, *part, run);
+                let (parse, solve) = (run.parse_time, run.solve_time);
+                ("ok", answer, format!("{parse:.2?}"), format!("{solve:.2?}"))
+            }
+            Err(_) => ("FAILED", String::new(), String::new(), String::new()),
+        };
+        println!(
+            "{:<4} {:<4} {:<6} {:<20} {:>12} {:>12}",
+            format!("{:02}", day.day()),
+            part.number(),
+            status,
+            answer,
+            parse,
+            solve
+        );
+    }
+
+    for (day, part, result) in outcomes {
+        if let Err(e) = result {
+            println!("\nDay {:02}, part {part}: {e}", day.day());
+        }
+    }
+
+    let cpu_time: Duration = outcomes
+        .iter()
+        .filter_map(|(_, _, r)| r.as_ref().ok())
+        .map(|run| run.parse_time + run.solve_time)
+        .sum();
+    println!("\nTotal wall time: {wall_time:.2?} [parse and solve of all parts: {cpu_time:.2?}]");
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    // Records hold only strings and numbers, serializing them can't fail.
+    serde_json::to_string_pretty(records).unwrap_or_default()
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+    let mut out = String::from("day,part,title,answer,parse_time_ns,solve_time_ns\n");
+    for r in records {
+        let answer = r.answer.as_deref().unwrap_or("");
+        out += &format!(
+            "{},{},{},{},{},{}\n",
+            r.day,
+            r.part,
+            csv_field(r.title),
+            csv_field(answer),
+            r.parse_time_ns,
+            r.solve_time_ns
+        );
+    }
+    out
+}
+
+// Quote a CSV field if it holds anything that would break the line apart.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(answer: Option<&str>) -> Record {
+        Record {
+            day: 5,
+            part: 1,
+            title: "Supply Stacks",
+            answer: answer.map(String::from),
+            rows: None,
+            parse_time_ns: 10,
+            solve_time_ns: 20,
+        }
+    }
+
+    #[test]
+    fn csv_quotes_fields() {
+        let csv = to_csv(&[record(Some("a,\"b\"")), record(None)]);
+        assert_eq!(
+            csv,
+            "day,part,title,answer,parse_time_ns,solve_time_ns\n\
+             5,1,Supply Stacks,\"a,\"\"b\"\"\",10,20\n\
+             5,1,Supply Stacks,,10,20\n"
+        );
+    }
+
+    #[test]
+    fn json_keeps_every_field() {
+        let json: serde_json::Value = serde_json::from_str(&to_json(&[record(None)])).unwrap();
+        let keys: Vec<&String> = json[0].as_object().unwrap().keys().collect();
+        assert_eq!(
+            keys,
+            [
+                "answer",
+                "day",
+                "parse_time_ns",
+                "part",
+                "rows",
+                "solve_time_ns",
+                "title"
+            ]
+        );
+        assert!(json[0]["answer"].is_null());
+    }
+}
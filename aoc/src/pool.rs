@@ -0,0 +1,150 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::sync::{Mutex, Once};
+use std::thread;
+
+thread_local! {
+    // Message of the last panic on this thread, with the place where it happened.
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+    // Set on pool threads, their panics are remembered instead of printed.
+    static IN_POOL: Cell<bool> = const { Cell::new(false) };
+}
+
+// Default hook prints every panic to stderr in the middle of other output. Panics of pool threads are remembered
+// instead and reported together with the task that panicked, others go to the hook that was set before.
+// Hook is set once for the whole process: swapping it around every run races when runs overlap,
+// and the silent one could be left in place for good.
+fn install_hook() {
+    static INSTALLED: Once = Once::new();
+    INSTALLED.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if IN_POOL.with(Cell::get) {
+                LAST_PANIC.with(|last| *last.borrow_mut() = Some(info.to_string()));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+// Run tasks on `jobs` threads. Results come back in the same order as tasks.
+// Panic in one task doesn't stop others, it's returned as an error with panic message instead.
+pub fn run<T, F>(jobs: usize, tasks: Vec<F>) -> Vec<Result<T, String>>
+where
+    T: Send,
+    F: FnOnce() -> T + Send,
+{
+    let amount = tasks.len();
+    let queue = Mutex::new(tasks.into_iter().enumerate());
+    let (sender, receiver) = mpsc::channel();
+
+    install_hook();
+
+    thread::scope(|s| {
+        for _ in 0..jobs.clamp(1, amount.max(1)) {
+            let sender = sender.clone();
+            let queue = &queue;
+            s.spawn(move || loop {
+                IN_POOL.with(|in_pool| in_pool.set(true));
+                // Lock is released before the task runs, so other threads can take their tasks meanwhile.
+                let next = queue.lock().map(|mut q| q.next());
+                let Ok(Some((index, task))) = next else {
+                    break;
+                };
+
+                let result = panic::catch_unwind(AssertUnwindSafe(task)).map_err(|payload| {
+                    LAST_PANIC
+                        .with(|last| last.borrow_mut().take())
+                        .unwrap_or_else(|| payload_message(payload.as_ref()))
+                });
+                // Receiver lives until every task is done.
+                let _ = sender.send((index, result));
+            });
+        }
+    });
+
+    drop(sender);
+
+    let mut results: Vec<(usize, Result<T, String>)> = receiver.into_iter().collect();
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+// Message a panic was started with. Hook sees it too, but it's not there when some other hook was set meanwhile.
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(s), _) => s.to_string(),
+        (_, Some(s)) => s.clone(),
+        _ => "panicked".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_keep_order_and_panics_are_caught() {
+        let tasks: Vec<Box<dyn FnOnce() -> usize + Send>> = vec![
+            Box::new(|| 1),
+            Box::new(|| panic!("task failed")),
+            Box::new(|| 3),
+        ];
+
+        let results = run(4, tasks);
+
+        assert_eq!(results[0], Ok(1));
+        assert!(results[1].as_ref().unwrap_err().contains("task failed"));
+        assert_eq!(results[2], Ok(3));
+    }
+
+    #[test]
+    fn single_job_and_no_tasks() {
+        let tasks: Vec<_> = (0..10).map(|i| move || i * 2).collect();
+        let results: Vec<usize> = run(1, tasks).into_iter().map(Result::unwrap).collect();
+        assert_eq!(results, (0..10).map(|i| i * 2).collect::<Vec<_>>());
+
+        let none: Vec<fn() -> u8> = Vec::new();
+        assert!(run(3, none).is_empty());
+    }
+
+    // Runs that overlap each catch their own panics, and panics outside of any pool are not swallowed after that.
+    #[test]
+    fn overlapping_runs() {
+        thread::scope(|s| {
+            for run_index in 0..4 {
+                s.spawn(move || {
+                    let tasks: Vec<_> = (0..20)
+                        .map(|i| {
+                            move || {
+                                if i % 3 == 0 {
+                                    panic!("run {run_index} task {i}")
+                                } else {
+                                    i
+                                }
+                            }
+                        })
+                        .collect();
+                    for (i, result) in run(3, tasks).into_iter().enumerate() {
+                        match result {
+                            Ok(n) => assert_eq!(n, i),
+                            Err(e) => {
+                                assert!(e.contains(&format!("run {run_index} task {i}")), "{e}")
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        let outside = panic::catch_unwind(|| panic!("outside of pool"));
+        assert!(outside.is_err());
+        assert_eq!(LAST_PANIC.with(|last| last.borrow_mut().take()), None);
+    }
+}
@@ -0,0 +1,348 @@
+use crate::verify::ANSWERS_FILE;
+use common::input::{day_input_path, default_inputs_dir};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+// Directory inside of inputs directory that holds one directory per profile.
+pub const PROFILES_DIR: &str = "profiles";
+// File inside of a profile directory with checksums of its inputs.
+pub const CHECKSUMS_FILE: &str = "checksums.toml";
+
+// Inputs of one account. Profile directory is laid out like an inputs directory (NN/input.txt and answers.toml),
+// so every command can use it in place of the default one. Answers differ between accounts, so each profile
+// gets its own answers file when the first input is added, without any answers until they are written into it.
+#[derive(Debug)]
+pub struct Profile {
+    pub name: String,
+    pub dir: PathBuf,
+    // SHA-256 of every added input, keyed by day ("01").
+    checksums: BTreeMap<String, String>,
+}
+
+// State of a stored input compared with its checksum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Integrity {
+    Ok,
+    // File was deleted after it was added.
+    Missing,
+    // File content changed. Hint tells what happened, if it's something common.
+    Modified(Option<&'static str>),
+}
+
+// Hex encoded SHA-256 of the content.
+pub fn checksum(content: &[u8]) -> String {
+    Sha256::digest(content)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+// Profile names become directory names, so only simple ones are allowed.
+fn check_name(name: &str) -> Result<(), Box<dyn Error>> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(
+            format!("Invalid profile name \"{name}\", use only letters, digits, '-' and '_'.")
+                .into(),
+        )
+    }
+}
+
+// Every profile inside of inputs directory, in order of names.
+pub fn profiles(inputs_dir: Option<&Path>) -> Result<Vec<Profile>, Box<dyn Error>> {
+    let root = profiles_dir(inputs_dir);
+    let Ok(entries) = std::fs::read_dir(&root) else {
+        return Ok(Vec::new());
+    };
+
+    let mut names = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            names.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    names.sort();
+    names
+        .iter()
+        .map(|name| Profile::open(inputs_dir, name))
+        .collect()
+}
+
+fn profiles_dir(inputs_dir: Option<&Path>) -> PathBuf {
+    match inputs_dir {
+        Some(d) => d.join(PROFILES_DIR),
+        None => default_inputs_dir().join(PROFILES_DIR),
+    }
+}
+
+impl Profile {
+    // Open a profile, it's empty if nothing was added to it yet.
+    pub fn open(inputs_dir: Option<&Path>, name: &str) -> Result<Self, Box<dyn Error>> {
+        check_name(name)?;
+        let dir = profiles_dir(inputs_dir).join(name);
+
+        let path = dir.join(CHECKSUMS_FILE);
+        let checksums = match std::fs::read_to_string(&path) {
+            Ok(file) => toml::from_str(&file).map_err(|e| format!("{}: {e}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(format!("{}: {e}", path.display()).into()),
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            dir,
+            checksums,
+        })
+    }
+
+    // Open a profile that is about to be used for running, it must have some inputs.
+    pub fn open_existing(inputs_dir: Option<&Path>, name: &str) -> Result<Self, Box<dyn Error>> {
+        let profile = Self::open(inputs_dir, name)?;
+        if profile.checksums.is_empty() {
+            return Err(format!(
+                "Profile \"{name}\" has no inputs, add them with `aoc input add <day> <file> --profile {name}`."
+            )
+            .into());
+        }
+        // Profiles made before answers files were created for them get one too.
+        profile.create_answers()?;
+        Ok(profile)
+    }
+
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        day_input_path(&self.dir, day)
+    }
+
+    // Days that have an input, in order.
+    pub fn days(&self) -> Vec<u8> {
+        self.checksums
+            .keys()
+            .filter_map(|d| d.parse().ok())
+            .collect()
+    }
+
+    // Copy an input into the profile and remember its checksum. Input that was already added
+    // with different content is only replaced with `force`, it may be the only copy of it.
+    pub fn add(&mut self, day: u8, file: &Path, force: bool) -> Result<PathBuf, Box<dyn Error>> {
+        let content = std::fs::read(file).map_err(|e| format!("{}: {e}", file.display()))?;
+        self.add_content(day, &content, force)
+    }
+
+    // Same as `add`, for an input that isn't in a file yet.
+    pub fn add_content(
+        &mut self,
+        day: u8,
+        content: &[u8],
+        force: bool,
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        let sum = checksum(content);
+
+        let key = format!("{day:02}");
+        if let Some(old) = self.checksums.get(&key) {
+            if *old != sum && !force {
+                return Err(format!(
+                    "Profile \"{}\" already has a different input for day {day:02}, use --force to replace it.",
+                    self.name
+                )
+                .into());
+            }
+        }
+
+        let path = self.input_path(day);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, content).map_err(|e| format!("{}: {e}", path.display()))?;
+
+        self.checksums.insert(key, sum);
+        self.save()?;
+        self.create_answers()?;
+        Ok(path)
+    }
+
+    // Answers file of the profile, for `aoc verify --profile`.
+    pub fn answers_path(&self) -> PathBuf {
+        self.dir.join(ANSWERS_FILE)
+    }
+
+    // Create an empty answers file, one that already exists keeps its answers.
+    fn create_answers(&self) -> Result<(), Box<dyn Error>> {
+        let path = self.answers_path();
+        if path.exists() {
+            return Ok(());
+        }
+
+        let file = format!(
+            "# Known answers for real inputs of profile \"{}\", checked by `aoc verify --profile {}`.\n\
+             # Tables are days, keys are parts, like in answers.toml of the repository.\n",
+            self.name, self.name
+        );
+        std::fs::write(&path, file).map_err(|e| format!("{}: {e}", path.display()).into())
+    }
+
+    fn save(&self) -> Result<(), Box<dyn Error>> {
+        let path = self.dir.join(CHECKSUMS_FILE);
+        let file = toml::to_string(&self.checksums)?;
+        std::fs::write(&path, file).map_err(|e| format!("{}: {e}", path.display()).into())
+    }
+
+    // Compare an input with the checksum it was added with.
+    pub fn check(&self, day: u8) -> Integrity {
+        let Some(expected) = self.checksums.get(&format!("{day:02}")) else {
+            return Integrity::Missing;
+        };
+        let Ok(content) = std::fs::read(self.input_path(day)) else {
+            return Integrity::Missing;
+        };
+        if checksum(&content) == *expected {
+            return Integrity::Ok;
+        }
+
+        // Editors like to add or strip the last newline, and some parsers depend on it.
+        let mut with_newline = content.clone();
+        with_newline.push(b'\n');
+        if checksum(&with_newline) == *expected {
+            return Integrity::Modified(Some("trailing newline was removed"));
+        }
+        if content.ends_with(b"\n") && checksum(&content[..content.len() - 1]) == *expected {
+            return Integrity::Modified(Some("trailing newline was added"));
+        }
+        let unix: Vec<u8> = content.iter().copied().filter(|b| *b != b'\r').collect();
+        if checksum(&unix) == *expected {
+            return Integrity::Modified(Some("line endings were changed to CRLF"));
+        }
+        Integrity::Modified(None)
+    }
+
+    // Error for the first input that doesn't match its checksum, so wrong answers aren't blamed on solvers.
+    pub fn verify(&self) -> Result<(), Box<dyn Error>> {
+        for day in self.days() {
+            let problem = match self.check(day) {
+                Integrity::Ok => continue,
+                Integrity::Missing => "it's missing".to_string(),
+                Integrity::Modified(Some(hint)) => format!("it was modified, {hint}"),
+                Integrity::Modified(None) => "it was modified".to_string(),
+            };
+            return Err(format!(
+                "Input of day {day:02} in profile \"{}\" doesn't match its checksum: {problem}. \
+                 Restore it, or add it again with --force if the change is intended.",
+                self.name
+            )
+            .into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-store-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn checksum_is_sha256() {
+        assert_eq!(
+            checksum(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn edits_are_detected() {
+        let dir = temp_dir("edits");
+        let source = dir.join("mine.txt");
+        std::fs::write(&source, "Monkey 0:\n").unwrap();
+
+        let mut profile = Profile::open(Some(&dir), "alice").unwrap();
+        let stored = profile.add(11, &source, false).unwrap();
+        assert_eq!(stored, dir.join("profiles/alice/11/input.txt"));
+
+        // Checksums survive reopening.
+        let profile = Profile::open_existing(Some(&dir), "alice").unwrap();
+        assert_eq!(profile.days(), [11]);
+        assert_eq!(profile.check(11), Integrity::Ok);
+        assert!(profile.verify().is_ok());
+
+        std::fs::write(&stored, "Monkey 0:").unwrap();
+        assert_eq!(
+            profile.check(11),
+            Integrity::Modified(Some("trailing newline was removed"))
+        );
+        std::fs::write(&stored, "Monkey 1:\n").unwrap();
+        assert_eq!(profile.check(11), Integrity::Modified(None));
+        assert!(profile.verify().is_err());
+
+        std::fs::remove_file(&stored).unwrap();
+        assert_eq!(profile.check(11), Integrity::Missing);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    // Verify reads answers of a profile from its directory, so adding an input makes sure they are there.
+    #[test]
+    fn answers_file_is_created() {
+        let dir = temp_dir("answers");
+        let source = dir.join("mine.txt");
+        std::fs::write(&source, "1\n").unwrap();
+
+        let mut profile = Profile::open(Some(&dir), "carol").unwrap();
+        profile.add(1, &source, false).unwrap();
+        let path = crate::verify::answers_path(Some(&profile.dir));
+        assert_eq!(path, profile.answers_path());
+        let answers = crate::verify::Answers::load(&path).unwrap();
+        assert_eq!(answers.get(1, common::Part::One), None);
+
+        // Recorded answers are never overwritten.
+        std::fs::write(&path, "[01]\n1 = 1\n").unwrap();
+        profile.add(2, &source, false).unwrap();
+        let answers = crate::verify::Answers::load(&path).unwrap();
+        assert_eq!(answers.get(1, common::Part::One), Some("1".to_string()));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn different_input_needs_force() {
+        let dir = temp_dir("force");
+        let (a, b) = (dir.join("a.txt"), dir.join("b.txt"));
+        std::fs::write(&a, "1\n").unwrap();
+        std::fs::write(&b, "2\n").unwrap();
+
+        let mut profile = Profile::open(Some(&dir), "bob").unwrap();
+        profile.add(1, &a, false).unwrap();
+        // Same content again is fine.
+        profile.add(1, &a, false).unwrap();
+        assert!(profile.add(1, &b, false).is_err());
+        profile.add(1, &b, true).unwrap();
+        assert_eq!(profile.check(1), Integrity::Ok);
+
+        let names: Vec<String> = profiles(Some(&dir))
+            .unwrap()
+            .into_iter()
+            .map(|p| p.name)
+            .collect();
+        assert_eq!(names, ["bob"]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn names_are_checked() {
+        assert!(Profile::open(None, "../other").is_err());
+        assert!(Profile::open(None, "").is_err());
+        assert!(Profile::open_existing(None, "nobody-has-this-profile").is_err());
+    }
+}
@@ -0,0 +1,208 @@
+use common::input::InputSource;
+use common::{Day, Part};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+// Name of the answers file inside of inputs directory.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+// Answer as it is written in answers file. Numbers can be written without quotes.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+enum Recorded {
+    Integer(i64),
+    Text(String),
+}
+
+impl Recorded {
+    fn to_answer_string(&self) -> String {
+        match self {
+            Recorded::Integer(n) => n.to_string(),
+            // Multi-line answers are easier to write with a trailing newline.
+            Recorded::Text(s) => s.trim_end_matches('\n').to_string(),
+        }
+    }
+}
+
+// Known answers for real inputs, keyed by day ("01") and then by part ("1").
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, Recorded>>);
+
+impl Answers {
+    pub fn parse(file: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(file)
+    }
+
+    // Read answers file. Error mentions the path, just like input errors do.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let file = std::fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        Self::parse(&file).map_err(|e| format!("{}: {e}", path.display()).into())
+    }
+
+    // Recorded answer of a part, if there is one.
+    pub fn get(&self, day: u8, part: Part) -> Option<String> {
+        self.0
+            .get(&format!("{day:02}"))
+            .and_then(|parts| parts.get(&part.to_string()))
+            .map(Recorded::to_answer_string)
+    }
+}
+
+// Answers file that belongs to inputs directory.
+pub fn answers_path(inputs_dir: Option<&Path>) -> PathBuf {
+    match inputs_dir {
+        Some(d) => d.join(ANSWERS_FILE),
+        None => common::input::default_inputs_dir().join(ANSWERS_FILE),
+    }
+}
+
+// Outcome of checking one part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    // Answer matches the recorded one.
+    Pass,
+    // Answer differs from the recorded one.
+    Fail { expected: String, found: String },
+    // Nothing is recorded for this part yet.
+    Unknown { found: String },
+    // Input could not be read or parsed.
+    Error(String),
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "FAIL",
+            Status::Unknown { .. } => "unknown",
+            Status::Error(_) => "ERROR",
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Fail { .. } | Status::Error(_))
+    }
+}
+
+// Result of checking one part of a day.
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub status: Status,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+// Run one part on its real input and compare the answer with the recorded one.
+pub fn check(day: &dyn Day, part: Part, answers: &Answers, inputs_dir: Option<&Path>) -> Check {
+    let mut check = Check {
+        day: day.day(),
+        part,
+        status: Status::Error(String::new()),
+        parse_time: Duration::ZERO,
+        solve_time: Duration::ZERO,
+    };
+
+    let input = InputSource::new(day.day(), None, inputs_dir);
+    let run = match input.read() {
+        Ok(file) => day.run(part, &file).map_err(|e| e.to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+
+    check.status = match run {
+        Ok(run) => {
+            check.parse_time = run.parse_time;
+            check.solve_time = run.solve_time;
+            let found = run.answer.to_string();
+            match answers.get(day.day(), part) {
+                Some(expected) if expected == found => Status::Pass,
+                Some(expected) => Status::Fail { expected, found },
+                None => Status::Unknown { found },
+            }
+        }
+        Err(e) => Status::Error(e),
+    };
+
+    check
+}
+
+// Print a table with one row per checked part, and details of everything that went wrong below it.
+pub fn print_report(checks: &[Check]) {
+    println!(
+        "{:<4} {:<4} {:<8} {:>12} {:>12}",
+        "Day", "Part", "Status", "Parse", "Solve"
+    );
+    for c in checks {
+        println!(
+            "{:<4} {:<4} {:<8} {:>12} {:>12}",
+            format!("{:02}", c.day),
+            c.part.number(),
+            c.status.label(),
+            format!("{:.2?}", c.parse_time),
+            format!("{:.2?}", c.solve_time),
+        );
+    }
+
+    for c in checks {
+        match &c.status {
+            Status::Pass => (),
+            Status::Fail { expected, found } => {
+                println!("\nDay {:02}, part {}: answer changed.", c.day, c.part);
+                println!("Expected:\n{expected}\nFound:\n{found}");
+            }
+            Status::Unknown { found } => {
+                println!("\nDay {:02}, part {}: no recorded answer.", c.day, c.part);
+                println!("Found:\n{found}");
+            }
+            Status::Error(e) => println!("\nDay {:02}, part {}: {e}", c.day, c.part),
+        }
+    }
+
+    let passed = checks.iter().filter(|c| c.status == Status::Pass).count();
+    let failed = checks.iter().filter(|c| c.status.is_failure()).count();
+    let total: Duration = checks.iter().map(|c| c.parse_time + c.solve_time).sum();
+    println!(
+        "\n{passed} passed, {failed} failed, {} unknown [total: {total:.2?}]",
+        checks.len() - passed - failed
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recorded_answers_parse() {
+        let answers = Answers::parse(
+            "[01]\n1 = 70296\n2 = \"205381\"\n\n[10]\n2 = \"\"\"\n#..\n.#.\n\"\"\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(1, Part::One).as_deref(), Some("70296"));
+        assert_eq!(answers.get(1, Part::Two).as_deref(), Some("205381"));
+        assert_eq!(answers.get(10, Part::Two).as_deref(), Some("#..\n.#."));
+        assert_eq!(answers.get(10, Part::One), None);
+    }
+
+    #[test]
+    fn repository_answers_cover_every_day() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(ANSWERS_FILE);
+        let answers = Answers::load(&path).unwrap();
+
+        for day in crate::days::DAYS {
+            for part in Part::ALL.into_iter().filter(|p| day.has_part(*p)) {
+                assert!(
+                    answers.get(day.day(), part).is_some(),
+                    "day {:02} part {part}",
+                    day.day()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use common::input::InputSource;
+use common::{Day, Part};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+
+// Answers of the previous run are handed over to the rebuilt runner through this variable.
+const STATE_VAR: &str = "AOC_WATCH_STATE";
+
+// Answer of one part on one input file, remembered to compare the next run with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Previous {
+    input: PathBuf,
+    part: u8,
+    // Answer or error message.
+    answer: String,
+    parse_ns: u64,
+    solve_ns: u64,
+}
+
+// Files that are watched and what is done when they change.
+pub struct Watch {
+    pub day: &'static dyn Day,
+    pub parts: Vec<Part>,
+    // Inputs every part is run on.
+    pub inputs: Vec<PathBuf>,
+    // Source directories, runner is rebuilt and restarted when anything inside of them changes.
+    pub sources: Vec<PathBuf>,
+    pub interval: Duration,
+}
+
+// Source directories of a day: its own crate and code shared by all days.
+// They only exist when runner is used from the repository it was built in.
+pub fn day_sources(day: u8) -> Vec<PathBuf> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    [
+        root.join(format!("{day:02}")).join("rust").join("src"),
+        root.join("common").join("src"),
+    ]
+    .into_iter()
+    .filter(|dir| dir.is_dir())
+    .collect()
+}
+
+// Modification times of every file under the paths. Missing files count too, so creating one is noticed.
+fn snapshot(paths: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let mut times = Vec::new();
+    for path in paths {
+        collect_times(path, &mut times);
+    }
+    times
+}
+
+fn collect_times(path: &Path, times: &mut Vec<(PathBuf, Option<SystemTime>)>) {
+    match std::fs::read_dir(path) {
+        Ok(entries) => {
+            let mut entries: Vec<PathBuf> = entries.flatten().map(|e| e.path()).collect();
+            entries.sort();
+            for entry in entries {
+                collect_times(&entry, times);
+            }
+        }
+        Err(_) => {
+            let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+            times.push((path.to_path_buf(), modified));
+        }
+    }
+}
+
+impl Watch {
+    // Run everything once, then again every time inputs change. Never returns unless something goes wrong.
+    pub fn run(&self) -> Result<(), Box<dyn Error>> {
+        let mut previous: Vec<Previous> = match std::env::var(STATE_VAR) {
+            Ok(state) => serde_json::from_str(&state).unwrap_or_default(),
+            Err(_) => Vec::new(),
+        };
+
+        let mut inputs = snapshot(&self.inputs);
+        let mut sources = snapshot(&self.sources);
+        previous = self.run_all(&previous);
+
+        loop {
+            std::thread::sleep(self.interval);
+
+            let new_sources = snapshot(&self.sources);
+            if new_sources != sources {
+                sources = new_sources;
+                println!(
+                    "\nSource of day {:02} changed, rebuilding...",
+                    self.day.day()
+                );
+                // Only comes back if build failed, new runner replaces this one otherwise.
+                if let Err(e) = restart(&previous) {
+                    println!("{e}");
+                }
+                continue;
+            }
+
+            let new_inputs = snapshot(&self.inputs);
+            if new_inputs != inputs {
+                inputs = new_inputs;
+                previous = self.run_all(&previous);
+            }
+        }
+    }
+
+    // Run every part on every input and print how answers and timings changed since previous run.
+    fn run_all(&self, previous: &[Previous]) -> Vec<Previous> {
+        let mut current = Vec::new();
+        for input in &self.inputs {
+            for part in &self.parts {
+                let result = InputSource::File(input.clone())
+                    .read()
+                    .map_err(|e| e.to_string())
+                    .and_then(|file| self.day.run(*part, &file).map_err(|e| e.to_string()));
+                let now = match result {
+                    Ok(run) => Previous {
+                        input: input.clone(),
+                        part: part.number(),
+                        answer: run.answer.to_string(),
+                        parse_ns: run.parse_time.as_nanos() as u64,
+                        solve_ns: run.solve_time.as_nanos() as u64,
+                    },
+                    Err(e) => Previous {
+                        input: input.clone(),
+                        part: part.number(),
+                        answer: format!("Error: {e}"),
+                        parse_ns: 0,
+                        solve_ns: 0,
+                    },
+                };
+
+                let before = previous
+                    .iter()
+                    .find(|p| p.input == now.input && p.part == now.part);
+                print_change(self.day.day(), before, &now);
+                current.push(now);
+            }
+        }
+        current
+    }
+}
+
+// Print a fresh answer, with a diff against the previous one and timing changes.
+fn print_change(day: u8, before: Option<&Previous>, now: &Previous) {
+    println!(
+        "\nDay {day:02}, part {} on {}:",
+        now.part,
+        now.input.display()
+    );
+    match before {
+        Some(before) if before.answer == now.answer => {
+            println!("{}\n(answer unchanged)", now.answer)
+        }
+        Some(before) => {
+            for line in answer_diff(&before.answer, &now.answer) {
+                println!("{line}");
+            }
+        }
+        None => println!("{}", now.answer),
+    }
+
+    let before = before.filter(|b| b.solve_ns > 0 || b.parse_ns > 0);
+    println!(
+        "parse: {}, solve: {}",
+        time_delta(before.map(|b| b.parse_ns), now.parse_ns),
+        time_delta(before.map(|b| b.solve_ns), now.solve_ns)
+    );
+}
+
+// Line by line diff of two answers, changed lines are shown as removed and added ones.
+fn answer_diff(old: &str, new: &str) -> Vec<String> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let mut diff = Vec::new();
+    for i in 0..old.len().max(new.len()) {
+        match (old.get(i), new.get(i)) {
+            (Some(o), Some(n)) if o == n => diff.push(format!("  {n}")),
+            (o, n) => {
+                if let Some(o) = o {
+                    diff.push(format!("- {o}"));
+                }
+                if let Some(n) = n {
+                    diff.push(format!("+ {n}"));
+                }
+            }
+        }
+    }
+    diff
+}
+
+// Time with its change since previous run.
+fn time_delta(before_ns: Option<u64>, now_ns: u64) -> String {
+    let now = Duration::from_nanos(now_ns);
+    match before_ns {
+        Some(before_ns) if before_ns > 0 => {
+            let before = Duration::from_nanos(before_ns);
+            let percent = (now_ns as f64 - before_ns as f64) / before_ns as f64 * 100.0;
+            let sign = if now >= before { '+' } else { '-' };
+            let delta = now.abs_diff(before);
+            format!("{now:.2?} ({sign}{delta:.2?}, {percent:+.1}%)")
+        }
+        _ => format!("{now:.2?}"),
+    }
+}
+
+// Rebuild the runner with the same profile and start it again with the same arguments,
+// handing over previous answers so the diff survives a restart.
+fn restart(previous: &[Previous]) -> Result<(), Box<dyn Error>> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut build = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    build.current_dir(&root).args(["build", "-p", "aoc"]);
+    if !cfg!(debug_assertions) {
+        build.arg("--release");
+    }
+    if !build.status()?.success() {
+        return Err("Build failed, waiting for the next change.".into());
+    }
+
+    let mut runner = Command::new(std::env::current_exe()?);
+    runner
+        .args(std::env::args_os().skip(1))
+        .env(STATE_VAR, serde_json::to_string(previous)?);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        // Only returns on error.
+        Err(runner.exec().into())
+    }
+    #[cfg(not(unix))]
+    {
+        std::process::exit(runner.status()?.code().unwrap_or(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_marks_changed_lines() {
+        assert_eq!(answer_diff("31", "29"), ["- 31", "+ 29"]);
+        assert_eq!(
+            answer_diff("#.\n.#", "#.\n##\n.."),
+            ["  #.", "- .#", "+ ##", "+ .."]
+        );
+    }
+
+    #[test]
+    fn timing_changes() {
+        assert_eq!(time_delta(None, 1_500), "1.50µs");
+        assert_eq!(time_delta(Some(2_000), 1_500), "1.50µs (-500.00ns, -25.0%)");
+        assert_eq!(time_delta(Some(1_000), 1_500), "1.50µs (+500.00ns, +50.0%)");
+    }
+
+    #[test]
+    fn missing_files_are_watched_too() {
+        let missing = PathBuf::from("/definitely/not/here.txt");
+        assert_eq!(snapshot(std::slice::from_ref(&missing)), [(missing, None)]);
+    }
+}
@@ -0,0 +1,219 @@
+use std::fmt;
+
+// Rendered image made out of lit and dark pixels, like day 10 CRT screen.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bitmap {
+    width: usize,
+    pixels: Vec<bool>,
+}
+
+impl Bitmap {
+    // Create a dark bitmap of required size.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            pixels: vec![false; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.len().checked_div(self.width).unwrap_or(0)
+    }
+
+    // Is pixel at x,y lit. Pixels outside of bitmap are dark.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.width
+            && self
+                .pixels
+                .get(y * self.width + x)
+                .copied()
+                .unwrap_or(false)
+    }
+
+    // Light up or darken pixel at x,y.
+    pub fn set(&mut self, x: usize, y: usize, lit: bool) {
+        if x < self.width {
+            if let Some(pixel) = self.pixels.get_mut(y * self.width + x) {
+                *pixel = lit;
+            }
+        }
+    }
+
+    // Each row rendered as '#' for lit and '.' for dark pixels.
+    pub fn rows(&self) -> Vec<String> {
+        self.pixels
+            .chunks(self.width.max(1))
+            .map(|row| row.iter().map(|p| if *p { '#' } else { '.' }).collect())
+            .collect()
+    }
+}
+
+// Width of one letter in the font that puzzles draw with, plus one column of space after it.
+const LETTER_WIDTH: usize = 4;
+const LETTER_SPACING: usize = 1;
+const LETTER_HEIGHT: usize = 6;
+
+// Letters of that font as they are drawn, row by row.
+const FONT: [(char, [&str; LETTER_HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...", "#...", ".#.#", "..#.", "..#.", "..#."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+impl Bitmap {
+    // Read letters drawn on the bitmap. None if bitmap doesn't hold only letters of the puzzle font.
+    pub fn letters(&self) -> Option<String> {
+        if self.height() != LETTER_HEIGHT || self.width == 0 {
+            return None;
+        }
+
+        let rows = self.rows();
+        let step = LETTER_WIDTH + LETTER_SPACING;
+        (0..self.width)
+            .step_by(step)
+            .map(|x| {
+                // Last letter might not have space after it.
+                let end = (x + LETTER_WIDTH).min(self.width);
+                let glyph: Vec<&str> = rows.iter().map(|row| &row[x..end]).collect();
+                FONT.iter()
+                    .find(|(_, font_glyph)| font_glyph[..] == glyph[..])
+                    .map(|(letter, _)| *letter)
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Bitmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.rows().join("\n"))
+    }
+}
+
+// Answer of one puzzle part in a form that is the same for every day.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    Bitmap(Bitmap),
+    // Input has no answer, e.g. day 06 marker that never appears.
+    None,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Bitmap(b) => write!(f, "{b}"),
+            Answer::None => write!(f, "none"),
+        }
+    }
+}
+
+// Integers that always fit into i64.
+macro_rules! impl_from_small_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Integer(n.into())
+            }
+        })*
+    };
+}
+
+// Integers that might not fit into i64. Those are kept as text instead of being truncated.
+macro_rules! impl_from_large_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                match i64::try_from(n) {
+                    Ok(n) => Answer::Integer(n),
+                    Err(_) => Answer::Text(n.to_string()),
+                }
+            }
+        })*
+    };
+}
+
+impl_from_small_integer!(u8, u16, u32, i8, i16, i32, i64);
+impl_from_large_integer!(u64, usize, u128, i128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl From<Bitmap> for Answer {
+    fn from(b: Bitmap) -> Self {
+        Answer::Bitmap(b)
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(v: Option<T>) -> Self {
+        match v {
+            Some(v) => v.into(),
+            None => Answer::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Draw text with the puzzle font.
+    fn draw(text: &str) -> Bitmap {
+        let mut bitmap = Bitmap::new(text.len() * (LETTER_WIDTH + LETTER_SPACING), LETTER_HEIGHT);
+        for (i, c) in text.chars().enumerate() {
+            let (_, glyph) = FONT.iter().find(|(letter, _)| *letter == c).unwrap();
+            for (y, row) in glyph.iter().enumerate() {
+                for (x, pixel) in row.chars().enumerate() {
+                    bitmap.set(i * (LETTER_WIDTH + LETTER_SPACING) + x, y, pixel == '#');
+                }
+            }
+        }
+        bitmap
+    }
+
+    #[test]
+    fn letters_are_read_back() {
+        let text: String = FONT.iter().map(|(letter, _)| *letter).collect();
+        assert_eq!(draw(&text).letters(), Some(text));
+    }
+
+    #[test]
+    fn unknown_pictures_are_not_letters() {
+        let mut bitmap = draw("EG");
+        bitmap.set(1, 1, true);
+        assert_eq!(bitmap.letters(), None);
+        assert_eq!(Bitmap::new(40, 5).letters(), None);
+        assert_eq!(Bitmap::new(0, 6).letters(), None);
+    }
+}
@@ -0,0 +1,184 @@
+use std::fmt;
+
+// How much of the offending input is shown in error messages.
+const FOUND_MAX_CHARS: usize = 30;
+
+// Error returned when puzzle input can't be parsed.
+// Points to the place in input where it went wrong and tells what was expected there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    // Puzzle day number.
+    pub day: u8,
+    // Line number, starting from 1.
+    pub line: usize,
+    // Column number in characters, starting from 1.
+    pub column: usize,
+    // What parser wanted to see.
+    pub expected: String,
+    // What it has found instead.
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        day: u8,
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        Self {
+            day,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    // Error at the start of `rest`, which is a part of `input` that is left to parse.
+    // What was found is the rest of that line.
+    pub fn at(day: u8, input: &str, rest: &str, expected: impl Into<String>) -> Self {
+        let offset = offset_in(input, rest);
+        let (line, column) = position(input, offset);
+
+        Self::new(day, line, column, expected, describe(&input[offset..]))
+    }
+
+    // Error at the very end of input, when something required never appeared.
+    pub fn at_end(day: u8, input: &str, expected: impl Into<String>) -> Self {
+        Self::at(day, input, &input[input.len()..], expected)
+    }
+
+    // Error inside of one line of input (line_index starts from 0) at position of `token`,
+    // which is a part of that line. Token is what was found.
+    pub fn in_line(
+        day: u8,
+        line_index: usize,
+        line: &str,
+        token: &str,
+        expected: impl Into<String>,
+    ) -> Self {
+        let column = line[..offset_in(line, token)].chars().count() + 1;
+        let found = if token.is_empty() {
+            describe(token)
+        } else {
+            quote(token)
+        };
+
+        Self::new(day, line_index + 1, column, expected, found)
+    }
+
+    // Same error, but for input that started `lines` lines later.
+    // Used when only a part of the file was given to the parser.
+    pub fn shift_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    // Error out of a failed nom parser that was parsing `input`.
+    pub fn from_nom(
+        day: u8,
+        input: &str,
+        err: nom::Err<nom::error::Error<&str>>,
+        expected: impl Into<String>,
+    ) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => Self::at(day, input, e.input, expected),
+            nom::Err::Incomplete(_) => Self::at_end(day, input, expected),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {:02}, line {}, column {}: expected {}, found {}",
+            self.day, self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Check nom result of parsing whole `input`. Everything except trailing whitespace must be consumed.
+pub fn finish<T>(
+    day: u8,
+    input: &str,
+    result: nom::IResult<&str, T>,
+    expected: &str,
+) -> Result<T, ParseError> {
+    match result {
+        Ok((rest, value)) => {
+            if rest.trim_start().is_empty() {
+                return Ok(value);
+            }
+
+            // Parsers stop at the first line they don't understand. Usually that's right before the newline
+            // that ends the last good line, so step over it to point to the start of the bad one. Whitespace
+            // is not skipped, a blank line or a stray \r is what the parser didn't understand.
+            let offset = offset_in(input, rest);
+            let rest = match rest.strip_prefix('\n') {
+                Some(next) if offset > 0 && !input[..offset].ends_with('\n') => next,
+                _ => rest,
+            };
+            let (line, column) = position(input, offset_in(input, rest));
+            let found = if rest.starts_with('\n') {
+                "blank line".to_string()
+            } else if rest.starts_with('\r') {
+                "unexpected \\r".to_string()
+            } else {
+                describe(rest)
+            };
+
+            Err(ParseError::new(day, line, column, expected, found))
+        }
+        Err(e) => Err(ParseError::from_nom(day, input, e, expected)),
+    }
+}
+
+// Byte offset of `part` inside of `whole`. Part must be a slice of whole.
+fn offset_in(whole: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).saturating_sub(whole.as_ptr() as usize);
+    let mut offset = offset.min(whole.len());
+    // Never point into the middle of a character.
+    while !whole.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+// Line and column of byte offset, both starting from 1.
+fn position(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let column = before[line_start..].chars().count() + 1;
+
+    (line, column)
+}
+
+// Describe what was found at the start of `rest`.
+fn describe(rest: &str) -> String {
+    if rest.is_empty() {
+        return "end of input".to_string();
+    }
+
+    let line = rest.lines().next().unwrap_or("");
+    if line.is_empty() {
+        "end of line".to_string()
+    } else {
+        quote(line)
+    }
+}
+
+// Quote found text, cutting it if it's too long.
+fn quote(s: &str) -> String {
+    if s.chars().count() > FOUND_MAX_CHARS {
+        let cut: String = s.chars().take(FOUND_MAX_CHARS).collect();
+        format!("{:?}...", cut)
+    } else {
+        format!("{s:?}")
+    }
+}
@@ -0,0 +1,121 @@
+use std::ops::RangeInclusive;
+
+// Small seeded random number generator (SplitMix64) for input generators.
+// Same seed gives the same input on every machine, which is all generators need. Not for anything secret.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // Number from 0 to n - 1. Zero n gives zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        // Multiply and take the high half, bias is far too small to matter for inputs.
+        ((u128::from(self.next_u64()) * u128::from(n)) >> 64) as u64
+    }
+
+    // Number inside of the range, both ends included.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = (*range.start(), *range.end());
+        if end <= start {
+            return start;
+        }
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.below(len),
+            // Range covers every u64.
+            None => self.next_u64(),
+        }
+    }
+
+    // Index into a slice of this length, which must not be empty.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    // True once in `n` times on average.
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.below(n) == 0
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    // Fisher-Yates shuffle.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.index(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+// How big a generated input is. Each day tells what these numbers mean for it,
+// values that are too small for a puzzle are raised to the smallest one it can work with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Size {
+    // Amount of main things in the input, like elves, moves or monkeys.
+    pub amount: usize,
+    // Width of a grid, or a day specific limit like the longest move.
+    pub width: usize,
+    // Height of a grid, or a day specific limit like the highest stack.
+    pub height: usize,
+}
+
+impl Default for Size {
+    fn default() -> Self {
+        Self {
+            amount: 1000,
+            width: 100,
+            height: 100,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let a: Vec<u64> = (0..5)
+            .scan(Rng::new(7), |r, _| Some(r.next_u64()))
+            .collect();
+        let b: Vec<u64> = (0..5)
+            .scan(Rng::new(7), |r, _| Some(r.next_u64()))
+            .collect();
+        let c: Vec<u64> = (0..5)
+            .scan(Rng::new(8), |r, _| Some(r.next_u64()))
+            .collect();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn ranges_are_respected() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!((3..=5).contains(&rng.range(3..=5)));
+            assert!(rng.below(10) < 10);
+        }
+        assert_eq!(rng.range(4..=4), 4);
+        assert_eq!(rng.below(0), 0);
+
+        let mut items: Vec<u32> = (0..20).collect();
+        rng.shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,260 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// Point or a vector on an integer plane. Just like in grids, y grows down.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    // Distance when moving only in straight lines.
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    // Distance when diagonal moves are allowed too, like a king on a chess board.
+    pub fn chebyshev(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    // Single step in the direction of this vector, each coordinate is -1, 0 or 1.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    // Position in a grid, None if any coordinate is negative.
+    pub fn to_position(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+}
+
+impl From<(usize, usize)> for Point2 {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x as i64, y as i64)
+    }
+}
+
+impl Add for Point2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point2 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point2 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Point2 {
+    type Output = Self;
+
+    fn mul(self, k: i64) -> Self {
+        Self::new(self.x * k, self.y * k)
+    }
+}
+
+// Diagonal direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Diagonal {
+    TopLeft,
+    TopRight,
+    BottomRight,
+    BottomLeft,
+}
+
+impl Diagonal {
+    pub const ALL: [Diagonal; 4] = [
+        Diagonal::TopLeft,
+        Diagonal::TopRight,
+        Diagonal::BottomRight,
+        Diagonal::BottomLeft,
+    ];
+
+    pub fn step(self) -> Point2 {
+        match self {
+            Diagonal::TopLeft => Point2::new(-1, -1),
+            Diagonal::TopRight => Point2::new(1, -1),
+            Diagonal::BottomRight => Point2::new(1, 1),
+            Diagonal::BottomLeft => Point2::new(-1, 1),
+        }
+    }
+}
+
+// Direction of a single step. Zero is standing still.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Zero,
+    Up,
+    Down,
+    Left,
+    Right,
+    Diag(Diagonal),
+}
+
+impl Direction {
+    // Straight directions, in order of up, down, left, right.
+    pub const STRAIGHT: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    // Every direction except Zero, straight ones first.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+        Direction::Diag(Diagonal::TopLeft),
+        Direction::Diag(Diagonal::TopRight),
+        Direction::Diag(Diagonal::BottomRight),
+        Direction::Diag(Diagonal::BottomLeft),
+    ];
+
+    // Vector of one step in this direction.
+    pub fn step(self) -> Point2 {
+        match self {
+            Direction::Zero => Point2::ORIGIN,
+            Direction::Up => Point2::new(0, -1),
+            Direction::Down => Point2::new(0, 1),
+            Direction::Left => Point2::new(-1, 0),
+            Direction::Right => Point2::new(1, 0),
+            Direction::Diag(d) => d.step(),
+        }
+    }
+
+    // Direction that moves closest towards the end of a vector.
+    pub fn towards(vector: Point2) -> Self {
+        match (vector.x.signum(), vector.y.signum()) {
+            (0, 0) => Direction::Zero,
+            (0, -1) => Direction::Up,
+            (0, _) => Direction::Down,
+            (-1, 0) => Direction::Left,
+            (_, 0) => Direction::Right,
+            (-1, -1) => Direction::Diag(Diagonal::TopLeft),
+            (_, -1) => Direction::Diag(Diagonal::TopRight),
+            (-1, _) => Direction::Diag(Diagonal::BottomLeft),
+            _ => Direction::Diag(Diagonal::BottomRight),
+        }
+    }
+}
+
+// Smallest rectangle that holds a set of points, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+impl BoundingBox {
+    // Box holding a single point.
+    pub fn new(point: Point2) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    // Box holding all points, None if there are none.
+    pub fn of(points: impl IntoIterator<Item = Point2>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::new(points.next()?);
+        for p in points {
+            bounds.include(p);
+        }
+        Some(bounds)
+    }
+
+    // Grow the box so it holds a point.
+    pub fn include(&mut self, p: Point2) {
+        self.min = Point2::new(self.min.x.min(p.x), self.min.y.min(p.y));
+        self.max = Point2::new(self.max.x.max(p.x), self.max.y.max(p.y));
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn width(&self) -> u64 {
+        self.min.x.abs_diff(self.max.x) + 1
+    }
+
+    pub fn height(&self) -> u64 {
+        self.min.y.abs_diff(self.max.y) + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(-3, 4);
+
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!((b - a).signum(), Point2::new(-1, 1));
+        assert_eq!(a + b, Point2::new(-2, 2));
+        assert_eq!(-a * 2, Point2::new(-2, 4));
+    }
+
+    #[test]
+    fn directions() {
+        for direction in Direction::ALL {
+            assert_eq!(Direction::towards(direction.step() * 3), direction);
+        }
+        assert_eq!(Direction::towards(Point2::ORIGIN), Direction::Zero);
+        assert_eq!(
+            Direction::towards(Point2::new(2, -1)),
+            Direction::Diag(Diagonal::TopRight)
+        );
+    }
+
+    #[test]
+    fn bounding_box() {
+        let points = [Point2::new(2, 3), Point2::new(-1, 5), Point2::new(0, 0)];
+        let bounds = BoundingBox::of(points).unwrap();
+
+        assert_eq!(bounds.min, Point2::new(-1, 0));
+        assert_eq!(bounds.max, Point2::new(2, 5));
+        assert_eq!((bounds.width(), bounds.height()), (4, 6));
+        assert!(bounds.contains(Point2::new(0, 4)));
+        assert!(!bounds.contains(Point2::new(3, 4)));
+        assert_eq!(BoundingBox::of([]), None);
+    }
+}
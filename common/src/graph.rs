@@ -0,0 +1,196 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+// Anything that can tell neighbours of its nodes and how much it costs to get to each of them.
+pub trait Graph {
+    type Node: Copy + Eq + Hash + Ord;
+
+    // Nodes reachable in one move from `node`, together with the cost of that move.
+    fn neighbours(&self, node: Self::Node) -> impl Iterator<Item = (Self::Node, u64)>;
+}
+
+// Path found by a search. Nodes go from one of the starts to the goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub cost: u64,
+    pub nodes: Vec<N>,
+}
+
+impl<N> Path<N> {
+    // Amount of moves done along the path.
+    pub fn steps(&self) -> usize {
+        self.nodes.len().saturating_sub(1)
+    }
+}
+
+// Walk back from the goal through nodes each node was reached from.
+fn reconstruct<N: Copy + Eq + Hash>(came_from: &HashMap<N, Option<N>>, goal: N) -> Vec<N> {
+    let mut nodes = vec![goal];
+    let mut current = goal;
+    while let Some(Some(previous)) = came_from.get(&current) {
+        nodes.push(*previous);
+        current = *previous;
+    }
+    nodes.reverse();
+    nodes
+}
+
+// Breadth first search. Every move counts as one step, costs of the graph are ignored.
+// Search starts from all `starts` at once and stops at the first node for which `is_goal` is true.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: impl Fn(G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    let mut came_from: HashMap<G::Node, Option<G::Node>> = HashMap::new();
+    let mut queue: VecDeque<(G::Node, u64)> = VecDeque::new();
+    for start in starts {
+        if came_from.insert(start, None).is_none() {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, steps)) = queue.pop_front() {
+        if is_goal(node) {
+            return Some(Path {
+                cost: steps,
+                nodes: reconstruct(&came_from, node),
+            });
+        }
+
+        for (neighbour, _) in graph.neighbours(node) {
+            if let Entry::Vacant(entry) = came_from.entry(neighbour) {
+                entry.insert(Some(node));
+                queue.push_back((neighbour, steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+// Dijkstra's algorithm, starting from all `starts` at once.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: impl Fn(G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    // It's A* that knows nothing about where the goal is.
+    search(graph, starts, is_goal, |_| 0)
+}
+
+// A* search towards `goal`. Heuristic must never overestimate remaining cost, otherwise path may not be the shortest.
+pub fn astar<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    goal: G::Node,
+    heuristic: impl Fn(G::Node) -> u64,
+) -> Option<Path<G::Node>> {
+    search(graph, starts, |n| n == goal, heuristic)
+}
+
+// Best first search with a binary heap, shared by Dijkstra and A*.
+fn search<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: impl Fn(G::Node) -> bool,
+    heuristic: impl Fn(G::Node) -> u64,
+) -> Option<Path<G::Node>> {
+    // Cheapest known cost of getting to each node.
+    let mut costs: HashMap<G::Node, u64> = HashMap::new();
+    let mut came_from: HashMap<G::Node, Option<G::Node>> = HashMap::new();
+    // Min heap ordered by cost plus heuristic. Stale entries are skipped instead of being removed.
+    let mut heap: BinaryHeap<Reverse<(u64, u64, G::Node)>> = BinaryHeap::new();
+
+    for start in starts {
+        costs.insert(start, 0);
+        came_from.insert(start, None);
+        heap.push(Reverse((heuristic(start), 0, start)));
+    }
+
+    while let Some(Reverse((_, cost, node))) = heap.pop() {
+        if costs.get(&node).is_some_and(|best| cost > *best) {
+            continue;
+        }
+        if is_goal(node) {
+            return Some(Path {
+                cost,
+                nodes: reconstruct(&came_from, node),
+            });
+        }
+
+        for (neighbour, move_cost) in graph.neighbours(node) {
+            let new_cost = cost + move_cost;
+            if costs.get(&neighbour).is_none_or(|best| new_cost < *best) {
+                costs.insert(neighbour, new_cost);
+                came_from.insert(neighbour, Some(node));
+                heap.push(Reverse((
+                    new_cost + heuristic(neighbour),
+                    new_cost,
+                    neighbour,
+                )));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Small weighted graph as an adjacency list.
+    struct Edges(Vec<Vec<(usize, u64)>>);
+
+    impl Graph for Edges {
+        type Node = usize;
+
+        fn neighbours(&self, node: usize) -> impl Iterator<Item = (usize, u64)> {
+            self.0[node].iter().copied()
+        }
+    }
+
+    // 0 -> 1 -> 3 is the shortest by steps, 0 -> 2 -> 1 -> 3 is the cheapest one.
+    fn edges() -> Edges {
+        Edges(vec![
+            vec![(1, 10), (2, 1)],
+            vec![(3, 1)],
+            vec![(1, 1)],
+            vec![],
+            vec![(3, 1)],
+        ])
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let path = bfs(&edges(), [0], |n| n == 3).unwrap();
+        assert_eq!(path.cost, 2);
+        assert_eq!(path.nodes, [0, 1, 3]);
+    }
+
+    #[test]
+    fn dijkstra_counts_costs() {
+        let path = dijkstra(&edges(), [0], |n| n == 3).unwrap();
+        assert_eq!(path.cost, 3);
+        assert_eq!(path.nodes, [0, 2, 1, 3]);
+        assert_eq!(path.steps(), 3);
+    }
+
+    #[test]
+    fn astar_with_zero_heuristic_is_dijkstra() {
+        let path = astar(&edges(), [0], 3, |_| 0).unwrap();
+        assert_eq!(path.cost, 3);
+    }
+
+    #[test]
+    fn multiple_starts_and_unreachable_goal() {
+        let path = dijkstra(&edges(), [0, 4], |n| n == 3).unwrap();
+        assert_eq!(path.nodes, [4, 3]);
+
+        assert_eq!(bfs(&edges(), [3], |n| n == 0), None);
+        assert_eq!(dijkstra(&edges(), [], |n| n == 0), None);
+    }
+}
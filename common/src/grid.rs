@@ -0,0 +1,317 @@
+use crate::error::ParseError;
+use crate::geometry::{Direction, Point2};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// Dense rectangular grid of cells, stored row after row.
+// Coordinates are (x, y) with (0, 0) in the top left corner, x grows to the right and y grows down.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    // Grid of given size with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // Grid out of cells listed row after row. None if amount of cells doesn't fit the width.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Option<Self> {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    // Parse a character map, one row per line. Every character is turned into a cell by `cell`,
+    // characters it returns None for are reported as errors telling that `expected` was expected there.
+    // Map must have at least one cell and all rows must be of the same width.
+    pub fn parse(
+        day: u8,
+        file: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        // Amount of chars in fist line is a width of the grid.
+        let width = file.lines().next().map(|l| l.chars().count()).unwrap_or(0);
+        if width == 0 {
+            return Err(ParseError::at_end(day, file, format!("row of {expected}")));
+        }
+
+        let mut cells = Vec::with_capacity(file.len());
+        for (y, line) in file.lines().enumerate() {
+            for (i, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    let token = &line[i..i + c.len_utf8()];
+                    ParseError::in_line(day, y, line, token, expected)
+                })?;
+                cells.push(value);
+            }
+
+            // All rows must be of the same width.
+            let line_width = line.chars().count();
+            if line_width != width {
+                let token = line
+                    .char_indices()
+                    .nth(width)
+                    .map_or(&line[line.len()..], |(i, _)| &line[i..]);
+                let expected = if line_width < width {
+                    format!("more of {expected}")
+                } else {
+                    "end of line".to_string()
+                };
+                return Err(ParseError::in_line(day, y, line, token, expected));
+            }
+        }
+
+        Ok(Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // Is this position inside of the grid.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    // Index of a cell in row after row order, for storing extra data of cells in plain lists.
+    pub fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        self.contains(x, y).then(|| y * self.width + x)
+    }
+
+    // Position of a cell from its index in row after row order.
+    pub fn position_of(&self, index: usize) -> Option<(usize, usize)> {
+        (index < self.cells.len()).then(|| (index % self.width, index / self.width))
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index_of(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index_of(x, y).map(|i| &mut self.cells[i])
+    }
+
+    // Set a cell. Positions outside of the grid are ignored.
+    pub fn set(&mut self, x: usize, y: usize, value: T) {
+        if let Some(cell) = self.get_mut(x, y) {
+            *cell = value;
+        }
+    }
+
+    // Position one step away from (x, y), if it's still inside of the grid.
+    pub fn step(&self, position: (usize, usize), step: Point2) -> Option<(usize, usize)> {
+        let (x, y) = (Point2::from(position) + step).to_position()?;
+        self.contains(x, y).then_some((x, y))
+    }
+
+    // Positions of up to 4 neighbours that are inside of the grid, order is up, down, left, right.
+    pub fn neighbours_4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::STRAIGHT
+            .into_iter()
+            .filter_map(move |d| self.step((x, y), d.step()))
+    }
+
+    // Positions of up to 8 neighbours that are inside of the grid, including diagonal ones.
+    pub fn neighbours_8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| self.step((x, y), d.step()))
+    }
+
+    // Cells of one row, from left to right.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> + '_ {
+        let start = (y.min(self.height)) * self.width;
+        let end = if y < self.height {
+            start + self.width
+        } else {
+            start
+        };
+        self.cells[start..end].iter()
+    }
+
+    // Cells of one column, from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        let height = if x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    // Cells met when walking from (x, y) in a direction until the edge of the grid.
+    // Starting cell is not included.
+    pub fn ray(&self, x: usize, y: usize, direction: Direction) -> impl Iterator<Item = &T> + '_ {
+        let step = direction.step();
+        let mut position = Some((x, y)).filter(|_| direction != Direction::Zero);
+        std::iter::from_fn(move || {
+            position = self.step(position?, step);
+            position.map(|(x, y)| &self[(x, y)])
+        })
+    }
+
+    // Positions of all cells, row after row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+    }
+
+    // Positions together with cells, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // Grid of the same size with every cell transformed.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // Draw the grid with one character per cell, rows joined by new lines.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for y in 0..self.height {
+            if y > 0 {
+                out.push('\n');
+            }
+            out.extend(self.row(y).map(&mut f));
+        }
+        out
+    }
+}
+
+// Indexing by (x, y) panics outside of the grid, just like slices do. Use get for checked access.
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.index_of(x, y) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "position ({x}, {y}) is outside of {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.index_of(x, y) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("position ({x}, {y}) is outside of {width}x{height} grid"),
+        }
+    }
+}
+
+// Every cell printed with its own Display, rows on separate lines.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(y) {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Diagonal;
+
+    fn digits(file: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(0, file, "digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parse_and_print() {
+        let grid = digits("123\n456\n").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string(), "123\n456");
+        assert_eq!(grid.render(|d| if *d > 3 { '#' } else { '.' }), "...\n###");
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(digits("").unwrap_err().expected, "row of digit");
+
+        let bad = digits("12\n1x\n").unwrap_err();
+        assert_eq!((bad.line, bad.column, bad.found.as_str()), (2, 2, "\"x\""));
+
+        let short = digits("12\n1\n").unwrap_err();
+        assert_eq!((short.line, short.column), (2, 2));
+
+        let long = digits("12\n123\n").unwrap_err();
+        assert_eq!(
+            (long.line, long.column, long.found.as_str()),
+            (2, 3, "\"3\"")
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = digits("123\n456\n789").unwrap();
+
+        assert_eq!(
+            grid.neighbours_4(0, 0).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours_4(1, 1).count(), 4);
+        assert_eq!(grid.neighbours_8(1, 1).count(), 8);
+        assert_eq!(grid.neighbours_8(2, 2).count(), 3);
+    }
+
+    #[test]
+    fn rows_columns_and_rays() {
+        let grid = digits("123\n456\n789").unwrap();
+
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), [4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6, 9]);
+        assert_eq!(grid.row(3).count(), 0);
+        assert_eq!(grid.column(3).count(), 0);
+        let bottom_right = Direction::Diag(Diagonal::BottomRight);
+        assert_eq!(
+            grid.ray(1, 2, Direction::Up).copied().collect::<Vec<_>>(),
+            [5, 2]
+        );
+        assert_eq!(
+            grid.ray(0, 0, bottom_right).copied().collect::<Vec<_>>(),
+            [5, 9]
+        );
+        assert_eq!(grid.ray(0, 0, Direction::Left).count(), 0);
+        assert_eq!(grid.ray(0, 0, Direction::Zero).count(), 0);
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+// Environment variable that overrides the default inputs directory.
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS";
+
+// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    // Read everything from standard input.
+    Stdin,
+    // Read a file at this path.
+    File(PathBuf),
+}
+
+impl InputSource {
+    // Pick an input source for a day.
+    // Explicit path wins, "-" means stdin, otherwise use NN/input.txt inside of inputs directory.
+    pub fn new(day: u8, path: Option<&Path>, inputs_dir: Option<&Path>) -> Self {
+        match path {
+            Some(p) if p == Path::new("-") => InputSource::Stdin,
+            Some(p) => InputSource::File(p.to_path_buf()),
+            None => {
+                let dir = match inputs_dir {
+                    Some(d) => d.to_path_buf(),
+                    None => default_inputs_dir(),
+                };
+                InputSource::File(day_input_path(&dir, day))
+            }
+        }
+    }
+
+    // Read whole input into a String.
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            // Add path to the error, otherwise "No such file or directory" is not really helpful.
+            InputSource::File(path) => std::fs::read_to_string(path)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display()))),
+        }
+    }
+
+    // Open input for reading it bit by bit, without loading all of it into memory.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::File(path) => File::open(path)
+                .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display()))),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+// Inputs directory from AOC_INPUTS variable, or the repository root that holds NN/input.txt files.
+pub fn default_inputs_dir() -> PathBuf {
+    match std::env::var_os(INPUTS_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join(".."),
+    }
+}
+
+// Path of a day input inside of inputs directory.
+pub fn day_input_path(inputs_dir: &Path, day: u8) -> PathBuf {
+    inputs_dir.join(format!("{day:02}")).join("input.txt")
+}
+
+// Read input for a standalone day binary. First command line argument is an optional path or "-".
+pub fn from_args(day: u8) -> io::Result<String> {
+    let path = std::env::args_os().nth(1).map(PathBuf::from);
+    InputSource::new(day, path.as_deref(), None).read()
+}
@@ -0,0 +1,19 @@
+// Code shared between every day and the runner.
+pub mod answer;
+pub mod error;
+pub mod generate;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod records;
+pub mod solution;
+pub mod stream;
+
+pub use answer::{Answer, Bitmap};
+pub use error::ParseError;
+pub use generate::{Rng, Size};
+pub use geometry::{Direction, Point2};
+pub use grid::Grid;
+pub use solution::{Day, Part, Run, Solution};
+pub use stream::ReadError;
@@ -0,0 +1,143 @@
+// Splitting input into records: groups of lines separated by blank lines, like elves of day 01 or monkeys of day 11.
+
+// Byte order mark that some editors put at the start of UTF-8 files.
+const BOM: char = '\u{feff}';
+
+// Input without byte order mark at its start. Parse what this returns, so errors point to the right column.
+pub fn strip_bom(file: &str) -> &str {
+    file.strip_prefix(BOM).unwrap_or(file)
+}
+
+// Line that separates records: empty, or only whitespace (including "\r" of CRLF endings).
+pub fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+// Group of lines between blank lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record<'a> {
+    // Index of the first line of the record in the whole input, from 0.
+    pub line_index: usize,
+    // Record from the start of its first line to the end of its last line, without the last line ending.
+    // Splitting a &str gives a part of it, so ParseError::at can point into the whole input with it.
+    pub text: &'a str,
+}
+
+impl<'a> Record<'a> {
+    // Lines of the record with their index in the whole input. Line endings are "\n" or "\r\n", like in `str::lines`.
+    pub fn lines(&self) -> impl Iterator<Item = (usize, &'a str)> + 'a {
+        let first = self.line_index;
+        self.text
+            .lines()
+            .enumerate()
+            .map(move |(i, line)| (first + i, line))
+    }
+}
+
+// Iterator over records of an input, see `records`.
+#[derive(Debug, Clone)]
+pub struct Records<'a> {
+    // Input that is not split yet.
+    rest: &'a str,
+    // Index of the first line of `rest`.
+    line_index: usize,
+}
+
+// Line at the start of `s` without its ending, and everything after that ending.
+fn split_line(s: &str) -> (&str, &str) {
+    match s.find('\n') {
+        Some(end) => {
+            let line = &s[..end];
+            (line.strip_suffix('\r').unwrap_or(line), &s[end + 1..])
+        }
+        None => (s, ""),
+    }
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = Record<'a>;
+
+    fn next(&mut self) -> Option<Record<'a>> {
+        // Skip blank lines before the record, there can be any amount of them or none at all.
+        loop {
+            if self.rest.is_empty() {
+                return None;
+            }
+            let (line, after) = split_line(self.rest);
+            if !is_blank(line) {
+                break;
+            }
+            self.rest = after;
+            self.line_index += 1;
+        }
+
+        // Record goes on until a blank line or the end of input, last line ending is optional.
+        let start = self.rest;
+        let first = self.line_index;
+        let mut text_end = 0;
+        while !self.rest.is_empty() {
+            let (line, after) = split_line(self.rest);
+            if is_blank(line) {
+                break;
+            }
+            text_end = start.len() - self.rest.len() + line.len();
+            self.rest = after;
+            self.line_index += 1;
+        }
+
+        Some(Record {
+            line_index: first,
+            text: &start[..text_end],
+        })
+    }
+}
+
+// Split input into records. Byte order mark, CRLF line endings, several blank lines in a row and blank lines at
+// the start or the end (or none there) are all fine. Strip BOM from input first if errors point into it.
+pub fn records(file: &str) -> Records<'_> {
+    let file = strip_bom(file);
+    Records {
+        rest: file,
+        line_index: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(file: &str) -> Vec<(usize, &str)> {
+        records(file).map(|r| (r.line_index, r.text)).collect()
+    }
+
+    #[test]
+    fn blank_lines_anywhere() {
+        let expected = vec![(0, "1\n2"), (3, "3")];
+        assert_eq!(texts("1\n2\n\n3"), expected);
+        assert_eq!(texts("1\n2\n\n3\n"), expected);
+        assert_eq!(texts("1\n2\n\n3\n\n\n"), expected);
+        assert_eq!(texts("\n1\n2\n\n3\n"), vec![(1, "1\n2"), (4, "3")]);
+        assert_eq!(texts("1\n2\n \t\n\n3"), vec![(0, "1\n2"), (4, "3")]);
+        assert!(texts("").is_empty());
+        assert!(texts("\n\n  \n").is_empty());
+    }
+
+    #[test]
+    fn crlf_and_bom() {
+        let file = "\u{feff}1\r\n2\r\n\r\n3\r\n";
+        assert_eq!(texts(file), vec![(0, "1\r\n2"), (3, "3")]);
+
+        let lines: Vec<(usize, &str)> = records(file).flat_map(|r| r.lines()).collect();
+        assert_eq!(lines, vec![(0, "1"), (1, "2"), (3, "3")]);
+        assert_eq!(strip_bom(file), &file[3..]);
+    }
+
+    // Text of a record is a part of the input, so errors can point into it.
+    #[test]
+    fn records_are_parts_of_input() {
+        let file = "a\n\nbc\n";
+        let record = records(file).nth(1).unwrap();
+        let error = crate::ParseError::at(1, file, &record.text[1..], "something");
+        assert_eq!((error.line, error.column), (3, 2));
+    }
+}
@@ -0,0 +1,147 @@
+use crate::answer::Answer;
+use crate::error::ParseError;
+use crate::generate::{Rng, Size};
+use crate::stream::ReadError;
+use std::fmt;
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+// Part of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    // Part from its number, 1 or 2.
+    pub fn from_number(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+// Solution of one puzzle day. Input is parsed once and then shared by both parts.
+pub trait Solution {
+    // Puzzle day number.
+    const DAY: u8;
+    // Puzzle title.
+    const TITLE: &'static str;
+    // Is part two solved. Unsolved part two should answer Answer::None.
+    const HAS_PART_TWO: bool = true;
+
+    // Parsed puzzle input.
+    type Input;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    fn parse(file: &str) -> Result<Self::Input, ParseError>;
+    fn part_one(input: &Self::Input) -> Self::Answer1;
+    fn part_two(input: &Self::Input) -> Self::Answer2;
+    // Random input that is valid for both parts, for stress testing.
+    fn generate(rng: &mut Rng, size: Size) -> String;
+
+    // Solve one part reading input bit by bit, so memory use doesn't grow with size of the input.
+    // Days that need whole input at once keep this default and answer None.
+    fn stream(part: Part, reader: &mut dyn BufRead) -> Option<Result<Answer, ReadError>> {
+        let _ = (part, reader);
+        None
+    }
+}
+
+// Answer of one part together with time it took to get it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+// Object safe view of a Solution, so days with different types can be stored in one list.
+pub trait Day: Sync {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn has_part(&self, part: Part) -> bool;
+    // Parse input and solve one part of it, timing both phases.
+    fn run(&self, part: Part, file: &str) -> Result<Run, ParseError>;
+    // Random valid input, same seed and size always give the same one.
+    fn generate(&self, seed: u64, size: Size) -> String;
+    // Solve one part reading input bit by bit. Parsing and solving happen together, so all of the time is
+    // counted as solve time. None if the day can't be solved this way.
+    fn run_streaming(&self, part: Part, reader: &mut dyn BufRead)
+        -> Option<Result<Run, ReadError>>;
+}
+
+impl<S: Solution + Sync> Day for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn has_part(&self, part: Part) -> bool {
+        match part {
+            Part::One => true,
+            Part::Two => S::HAS_PART_TWO,
+        }
+    }
+
+    fn run(&self, part: Part, file: &str) -> Result<Run, ParseError> {
+        let start = Instant::now();
+        let input = S::parse(file)?;
+        let parse_time = start.elapsed();
+
+        let start = Instant::now();
+        let answer = match part {
+            Part::One => S::part_one(&input).into(),
+            Part::Two => S::part_two(&input).into(),
+        };
+        let solve_time = start.elapsed();
+
+        Ok(Run {
+            answer,
+            parse_time,
+            solve_time,
+        })
+    }
+
+    fn generate(&self, seed: u64, size: Size) -> String {
+        S::generate(&mut Rng::new(seed), size)
+    }
+
+    fn run_streaming(
+        &self,
+        part: Part,
+        reader: &mut dyn BufRead,
+    ) -> Option<Result<Run, ReadError>> {
+        let start = Instant::now();
+        let answer = S::stream(part, reader)?;
+        let solve_time = start.elapsed();
+
+        Some(answer.map(|answer| Run {
+            answer,
+            parse_time: Duration::ZERO,
+            solve_time,
+        }))
+    }
+}
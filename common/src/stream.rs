@@ -0,0 +1,169 @@
+use crate::error::ParseError;
+use crate::records::{is_blank, strip_bom, Record};
+use std::fmt;
+use std::io::{self, BufRead};
+
+// Error of a solution that reads its input as a stream: reading can fail on its own, unlike with a String.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "{e}"),
+            ReadError::Parse(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Io(e) => Some(e),
+            ReadError::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(e: ParseError) -> Self {
+        ReadError::Parse(e)
+    }
+}
+
+// Call `f` with index (starting from 0) and text of every line, like `str::lines` does, but reading one line
+// at a time. Only the current line is kept in memory, so input can be as big as it wants.
+pub fn for_each_line(
+    mut reader: impl BufRead,
+    mut f: impl FnMut(usize, &str) -> Result<(), ParseError>,
+) -> Result<(), ReadError> {
+    let mut buffer = String::new();
+    for index in 0.. {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            break;
+        }
+
+        // Line ends with "\n" or "\r\n", except the last one which may have no ending at all.
+        let line = match buffer.strip_suffix('\n') {
+            Some(line) => line.strip_suffix('\r').unwrap_or(line),
+            None => &buffer,
+        };
+        let line = if index == 0 { strip_bom(line) } else { line };
+        f(index, line)?;
+    }
+
+    Ok(())
+}
+
+// Call `f` with every record of input, split the same way as `records::records` does, but reading one line
+// at a time. Only the current record is kept in memory.
+pub fn for_each_record(
+    reader: impl BufRead,
+    mut f: impl FnMut(Record<'_>) -> Result<(), ParseError>,
+) -> Result<(), ReadError> {
+    // Lines of the current record joined with "\n", and index of its first line.
+    let mut text = String::new();
+    let mut first_line = 0;
+
+    for_each_line(reader, |i, line| {
+        if is_blank(line) {
+            if !text.is_empty() {
+                f(Record {
+                    line_index: first_line,
+                    text: &text,
+                })?;
+                text.clear();
+            }
+        } else {
+            if text.is_empty() {
+                first_line = i;
+            } else {
+                text.push('\n');
+            }
+            text.push_str(line);
+        }
+        Ok(())
+    })?;
+
+    // Input doesn't have to end with a blank line.
+    if !text.is_empty() {
+        f(Record {
+            line_index: first_line,
+            text: &text,
+        })?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::records::records;
+
+    fn lines_of(text: &str) -> Vec<(usize, String)> {
+        let mut lines = Vec::new();
+        for_each_line(text.as_bytes(), |i, line| {
+            lines.push((i, line.to_string()));
+            Ok(())
+        })
+        .unwrap();
+        lines
+    }
+
+    #[test]
+    fn same_lines_as_str_lines() {
+        for text in ["a\nb\n", "a\r\nb", "a\n\nb\n\n", "", "\n", "a\rb\r\n"] {
+            let expected: Vec<(usize, String)> =
+                text.lines().map(String::from).enumerate().collect();
+            assert_eq!(lines_of(text), expected, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn same_records_as_records() {
+        let files = ["\u{feff}1\r\n2\r\n\r\n3\r\n", "\n\n1\n \n\n2\n3", "1", ""];
+        for file in files {
+            let mut streamed: Vec<(usize, String)> = Vec::new();
+            for_each_record(file.as_bytes(), |record| {
+                streamed.push((record.line_index, record.lines().map(|(_, l)| l).collect()));
+                Ok(())
+            })
+            .unwrap();
+
+            let expected: Vec<(usize, String)> = records(file)
+                .map(|r| (r.line_index, r.lines().map(|(_, l)| l).collect()))
+                .collect();
+            assert_eq!(streamed, expected, "{file:?}");
+        }
+    }
+
+    #[test]
+    fn errors_stop_reading() {
+        let mut seen = 0;
+        let result = for_each_line("1\n2\n3\n".as_bytes(), |i, line| {
+            seen += 1;
+            match line {
+                "2" => Err(ParseError::in_line(1, i, line, line, "odd number")),
+                _ => Ok(()),
+            }
+        });
+
+        assert!(matches!(result, Err(ReadError::Parse(e)) if e.line == 2));
+        assert_eq!(seen, 2);
+
+        let invalid: &[u8] = b"ok\n\xff\n";
+        let result = for_each_line(invalid, |_, _| Ok(()));
+        assert!(matches!(result, Err(ReadError::Io(_))));
+    }
+}
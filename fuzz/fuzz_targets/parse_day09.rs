@@ -0,0 +1,7 @@
+#![no_main]
+// Day 09 parser must turn any input into a structured error instead of panicking.
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    aoc_fuzz::parse::<day09::Day09>(data);
+});
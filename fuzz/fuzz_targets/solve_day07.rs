@@ -0,0 +1,7 @@
+#![no_main]
+// Anything that day 07 parser accepts must be solved without panicking.
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    aoc_fuzz::solve::<day07::Day07>(data);
+});
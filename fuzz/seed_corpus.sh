@@ -0,0 +1,16 @@
+#!/bin/sh
+# Fill corpus of every fuzz target with real and example inputs of its day, so fuzzing starts from files
+# that already reach deep into the solutions. Run from anywhere, existing corpus files are kept.
+set -e
+cd "$(dirname "$0")"
+
+for target in fuzz_targets/*.rs; do
+    name=$(basename "$target" .rs)
+    day=${name##*_day}
+    mkdir -p "corpus/$name"
+    for input in ../"$day"/input.txt ../"$day"/test_input*.txt; do
+        if [ -f "$input" ]; then
+            cp "$input" "corpus/$name/$(basename "$input")"
+        fi
+    done
+done
@@ -0,0 +1,19 @@
+// Checks shared by fuzz targets. Any panic inside of them is a bug that fuzzer reports,
+// bad input must only ever end up as a ParseError.
+use common::Solution;
+
+// Parse fuzzer data as input of a day. Data that isn't UTF-8 can't be a puzzle input, runner refuses it before parsing.
+pub fn parse<S: Solution>(data: &[u8]) -> Option<S::Input> {
+    let file = std::str::from_utf8(data).ok()?;
+    S::parse(file).ok()
+}
+
+// Parse fuzzer data and solve every part of anything that parses.
+pub fn solve<S: Solution>(data: &[u8]) {
+    if let Some(input) = parse::<S>(data) {
+        S::part_one(&input);
+        if S::HAS_PART_TWO {
+            S::part_two(&input);
+        }
+    }
+}